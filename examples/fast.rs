@@ -0,0 +1,27 @@
+use meth::Vec;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: usize = 100_000;
+
+fn main() {
+    let vec = Vec::<f32, 64>::from_array(core::array::from_fn(|i| i as f32 * 0.37 - 12.0));
+
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        black_box(black_box(vec).sin());
+    }
+
+    let exact = start.elapsed();
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        black_box(black_box(vec).fast_sin());
+    }
+
+    let fast = start.elapsed();
+
+    println!("vec.sin() = {exact:?}");
+    println!("vec.fast_sin() = {fast:?}");
+}
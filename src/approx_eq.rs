@@ -0,0 +1,133 @@
+use crate::Real;
+
+/// Approximate equality, for types containing floats.
+pub trait ApproxEq {
+    /// Type of the tolerance.
+    type Epsilon: Copy;
+
+    /// Default absolute tolerance.
+    const DEFAULT_EPSILON: Self::Epsilon;
+
+    /// Default relative tolerance.
+    const DEFAULT_MAX_RELATIVE: Self::Epsilon;
+
+    /// Default tolerance, in units in the last place.
+    const DEFAULT_MAX_ULPS: u32;
+
+    /// Returns `true` if the absolute difference of `self` and `other` is at most `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::ApproxEq;
+    ///
+    /// assert!(1.0_f32.abs_diff_eq(&1.05, 0.1));
+    /// assert!(!1.0_f32.abs_diff_eq(&1.2, 0.1));
+    /// ```
+    #[must_use]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    /// Returns `true` if the absolute difference of `self` and `other` is at most `epsilon`, or
+    /// at most `max_relative` times the larger magnitude of `self` and `other`.
+    ///
+    /// Infinities are only equal to infinities of the same sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::ApproxEq;
+    ///
+    /// assert!(1000.0_f32.relative_eq(&1001.0, f32::EPSILON, 0.01));
+    /// assert!(!1000.0_f32.relative_eq(&1100.0, f32::EPSILON, 0.01));
+    /// ```
+    #[must_use]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    /// Returns `true` if the absolute difference of `self` and `other` is at most `epsilon`, or
+    /// if they are at most `max_ulps` representable floats apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::ApproxEq;
+    ///
+    /// let x = 1.0_f32;
+    /// let y = f32::from_bits(x.to_bits() + 2);
+    ///
+    /// assert!(x.ulps_eq(&y, 0.0, 2));
+    /// assert!(!x.ulps_eq(&y, 0.0, 1));
+    /// ```
+    #[must_use]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_approx_eq {
+    { $ty:ident, $bits:ty, $sign:literal } => {
+        impl const ApproxEq for $ty {
+            type Epsilon = $ty;
+
+            const DEFAULT_EPSILON: $ty = <$ty as Real>::EPSILON;
+            const DEFAULT_MAX_RELATIVE: $ty = <$ty as Real>::EPSILON;
+            const DEFAULT_MAX_ULPS: u32 = 4;
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$ty, epsilon: $ty) -> bool {
+                *self == *other || Real::abs(*self - *other) <= epsilon
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &$ty, epsilon: $ty, max_relative: $ty) -> bool {
+                if *self == *other {
+                    return true;
+                }
+
+                let difference = Real::abs(*self - *other);
+
+                // infinities of different signs, or an infinity and a finite number
+                if difference == <$ty as Real>::INFINITY {
+                    return false;
+                }
+
+                if difference <= epsilon {
+                    return true;
+                }
+
+                let largest = Real::max(Real::abs(*self), Real::abs(*other));
+
+                difference <= largest * max_relative
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$ty, epsilon: $ty, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+
+                #[allow(clippy::eq_op)]
+                if *self != *self || *other != *other {
+                    return false;
+                }
+
+                let a = self.to_bits();
+                let b = other.to_bits();
+
+                // different signs
+                if (a ^ b) & $sign != 0 {
+                    return false;
+                }
+
+                let ulps = if a > b { a - b } else { b - a };
+
+                ulps <= max_ulps as $bits
+            }
+        }
+    }
+}
+
+impl_approx_eq! { f32, u32, 0x8000_0000 }
+impl_approx_eq! { f64, u64, 0x8000_0000_0000_0000 }
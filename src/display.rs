@@ -0,0 +1,193 @@
+//! Helpers for `Display` implementations, without `alloc`.
+
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Counts characters written, used to measure formatted elements.
+struct Counter(usize);
+
+impl Write for Counter {
+    #[inline]
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.0 += string.chars().count();
+
+        Ok(())
+    }
+}
+
+/// Precision, and sign of a formatter, width and fill are handled by the caller.
+#[derive(Clone, Copy)]
+pub(crate) struct Options {
+    precision: Option<usize>,
+    sign_plus: bool,
+}
+
+impl Options {
+    #[inline]
+    pub(crate) fn new(fmt: &Formatter) -> Options {
+        Options {
+            precision: fmt.precision(),
+            sign_plus: fmt.sign_plus(),
+        }
+    }
+
+    /// Same options, but always print the sign.
+    #[inline]
+    pub(crate) fn with_sign(self) -> Options {
+        Options {
+            sign_plus: true,
+            ..self
+        }
+    }
+
+    /// Writes `value` with these options.
+    #[inline]
+    pub(crate) fn write<T, W>(self, writer: &mut W, value: &T) -> fmt::Result
+    where
+        T: Display,
+        W: Write,
+    {
+        match (self.precision, self.sign_plus) {
+            (Some(precision), false) => write!(writer, "{value:.precision$}"),
+            (Some(precision), true) => write!(writer, "{value:+.precision$}"),
+            (None, false) => write!(writer, "{value}"),
+            (None, true) => write!(writer, "{value:+}"),
+        }
+    }
+
+    /// Number of characters `value` is written as with these options.
+    #[inline]
+    pub(crate) fn len<T>(self, value: &T) -> usize
+    where
+        T: Display,
+    {
+        let mut counter = Counter(0);
+        let _ = self.write(&mut counter, value);
+
+        counter.0
+    }
+}
+
+/// Writes `(a, b, c)`, or one element per line for `{:#}`.
+pub(crate) fn tuple<T>(fmt: &mut Formatter, elements: &[T]) -> fmt::Result
+where
+    T: Display,
+{
+    let alternate = fmt.alternate();
+
+    fmt.write_str("(")?;
+
+    for (i, element) in elements.iter().enumerate() {
+        if alternate {
+            fmt.write_str("\n    ")?;
+        } else if i != 0 {
+            fmt.write_str(", ")?;
+        }
+
+        Display::fmt(element, fmt)?;
+
+        if alternate {
+            fmt.write_str(",")?;
+        }
+    }
+
+    if alternate && !elements.is_empty() {
+        fmt.write_str("\n")?;
+    }
+
+    fmt.write_str(")")
+}
+
+/// Writes `(a, b, c)`, or `(x: a, y: b, z: c)` for `{:#}`.
+pub(crate) fn fields<T>(fmt: &mut Formatter, names: &[&str], elements: &[&T]) -> fmt::Result
+where
+    T: Display,
+{
+    let alternate = fmt.alternate();
+
+    fmt.write_str("(")?;
+
+    for (i, (name, element)) in names.iter().zip(elements).enumerate() {
+        if i != 0 {
+            fmt.write_str(", ")?;
+        }
+
+        if alternate {
+            fmt.write_str(name)?;
+            fmt.write_str(": ")?;
+        }
+
+        Display::fmt(*element, fmt)?;
+    }
+
+    fmt.write_str(")")
+}
+
+/// Writes a row-major grid with right-aligned columns, one row per line, or `[[a, b], [c, d]]`
+/// for `{:#}`.
+///
+/// Each column is as wide as its widest element, or the formatter width if larger.
+pub(crate) fn grid<T>(fmt: &mut Formatter, elements: &[T], columns: usize) -> fmt::Result
+where
+    T: Display,
+{
+    if columns == 0 {
+        return Ok(());
+    }
+
+    if fmt.alternate() {
+        fmt.write_str("[")?;
+
+        for (row, elements) in elements.chunks(columns).enumerate() {
+            if row != 0 {
+                fmt.write_str(", ")?;
+            }
+
+            fmt.write_str("[")?;
+
+            for (column, element) in elements.iter().enumerate() {
+                if column != 0 {
+                    fmt.write_str(", ")?;
+                }
+
+                Display::fmt(element, fmt)?;
+            }
+
+            fmt.write_str("]")?;
+        }
+
+        return fmt.write_str("]");
+    }
+
+    let options = Options::new(fmt);
+    let min_width = fmt.width().unwrap_or(0);
+
+    for (row, row_elements) in elements.chunks(columns).enumerate() {
+        if row != 0 {
+            fmt.write_str("\n")?;
+        }
+
+        fmt.write_str("[")?;
+
+        for (column, element) in row_elements.iter().enumerate() {
+            if column != 0 {
+                fmt.write_str(", ")?;
+            }
+
+            let width = elements[column..]
+                .iter()
+                .step_by(columns)
+                .map(|element| options.len(element))
+                .fold(min_width, usize::max);
+
+            for _ in options.len(element)..width {
+                fmt.write_char(' ')?;
+            }
+
+            options.write(fmt, element)?;
+        }
+
+        fmt.write_str("]")?;
+    }
+
+    Ok(())
+}
@@ -1,4 +1,9 @@
-use crate::{Quaternion, Real};
+use crate::identity::Zero;
+use crate::{ApproxEq, ParseError, Quaternion, Real};
+use core::cmp::{Eq, PartialEq};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 #[derive(Clone, Copy, Debug)]
 pub struct EulerAngles<T> {
@@ -13,6 +18,111 @@ impl<T> EulerAngles<T> {
     }
 }
 
+impl<T> const PartialEq for EulerAngles<T>
+where
+    T: ~const PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &EulerAngles<T>) -> bool {
+        self.pitch == other.pitch && self.yaw == other.yaw && self.roll == other.roll
+    }
+}
+
+impl<T> Eq for EulerAngles<T> where T: Eq {}
+
+impl<T> Hash for EulerAngles<T>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pitch.hash(state);
+        self.yaw.hash(state);
+        self.roll.hash(state);
+    }
+}
+
+impl<T> const Default for EulerAngles<T>
+where
+    T: ~const Zero,
+{
+    /// Creates new euler angles with all angles set to zero.
+    #[inline]
+    fn default() -> EulerAngles<T> {
+        Self::new(Zero::zero(), Zero::zero(), Zero::zero())
+    }
+}
+
+/// Component-wise approximate equality.
+impl<T> const ApproxEq for EulerAngles<T>
+where
+    T: ~const ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    const DEFAULT_EPSILON: T::Epsilon = T::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T::Epsilon = T::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = T::DEFAULT_MAX_ULPS;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &EulerAngles<T>, epsilon: T::Epsilon) -> bool {
+        self.pitch.abs_diff_eq(&other.pitch, epsilon)
+            && self.yaw.abs_diff_eq(&other.yaw, epsilon)
+            && self.roll.abs_diff_eq(&other.roll, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &EulerAngles<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> bool {
+        self.pitch.relative_eq(&other.pitch, epsilon, max_relative)
+            && self.yaw.relative_eq(&other.yaw, epsilon, max_relative)
+            && self.roll.relative_eq(&other.roll, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &EulerAngles<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.pitch.ulps_eq(&other.pitch, epsilon, max_ulps)
+            && self.yaw.ulps_eq(&other.yaw, epsilon, max_ulps)
+            && self.roll.ulps_eq(&other.roll, epsilon, max_ulps)
+    }
+}
+
+/// Formats as `(pitch, yaw, roll)`, or `(pitch: 1, yaw: 2, roll: 3)` for `{:#}`.
+///
+/// Width, and precision apply to each angle.
+impl<T> fmt::Display for EulerAngles<T>
+where
+    T: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::fields(
+            fmt,
+            &["pitch", "yaw", "roll"],
+            &[&self.pitch, &self.yaw, &self.roll],
+        )
+    }
+}
+
+/// Parses `pitch`, `yaw`, and `roll` the same as `Vec`, such as `0 90 0`, or `(0, 90, 0)`.
+impl<T> FromStr for EulerAngles<T>
+where
+    T: FromStr,
+{
+    type Err = ParseError<T::Err>;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<EulerAngles<T>, ParseError<T::Err>> {
+        let [pitch, yaw, roll] = crate::parse::parse_array(string, None)?;
+
+        Ok(Self::new(pitch, yaw, roll))
+    }
+}
+
 impl const From<Quaternion<f32>> for EulerAngles<f32> {
     fn from(quarernion: Quaternion<f32>) -> Self {
         // roll (x-axis rotation)
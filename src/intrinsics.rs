@@ -1,4 +1,4 @@
-use cake::mem;
+use crate::Simd;
 
 // arithmetic
 
@@ -14,25 +14,69 @@ pub use mul::simd_mul;
 pub use rem::simd_rem;
 pub use sub::simd_sub;
 
+// float ops
+
+mod ceil;
+mod fabs;
+mod floor;
+mod fma;
+mod fmax;
+mod fmin;
+mod fsqrt;
+mod round;
+
+pub use ceil::simd_ceil;
+pub use fabs::simd_fabs;
+pub use floor::simd_floor;
+pub use fma::simd_fma;
+pub use fmax::simd_fmax;
+pub use fmin::simd_fmin;
+pub use fsqrt::simd_fsqrt;
+pub use round::simd_round;
+
 // cast
 
 mod cast;
 
-pub use cast::{simd_cast, simd_cast_mask};
+pub use cast::{simd_as, simd_cast, simd_cast_mask};
 
 // use an identity (one, zero)
 
 mod product;
+mod product_unordered;
 mod sum;
+mod sum_unordered;
 
 pub use product::simd_product;
+pub use product_unordered::simd_product_unordered;
 pub use sum::simd_sum;
+pub use sum_unordered::simd_sum_unordered;
+
+// horizontal reductions
+
+mod reduce_and;
+mod reduce_max;
+mod reduce_min;
+mod reduce_or;
+mod reduce_xor;
+
+pub use reduce_and::simd_reduce_and;
+pub use reduce_max::simd_reduce_max;
+pub use reduce_min::simd_reduce_min;
+pub use reduce_or::simd_reduce_or;
+pub use reduce_xor::simd_reduce_xor;
 
 // bitwise ops
 
 mod and;
+mod or;
+mod shl;
+mod shr;
 
 pub use and::simd_and;
+pub use or::simd_or;
+pub use shl::simd_shl;
+pub use shr::simd_shr;
 
 // logic ops
 
@@ -50,26 +94,40 @@ pub use le::simd_le;
 pub use lt::simd_lt;
 pub use ne::simd_ne;
 
-#[derive(Clone, Copy)]
-#[repr(simd)]
-struct Simd<T, const N: usize>(pub [T; N]);
-
-impl<T, const N: usize> Simd<T, N> {
-    #[inline]
-    pub const fn from_array(array: [T; N]) -> Self {
-        Self(array)
-    }
-
-    #[inline]
-    pub const fn to_array(self) -> [T; N]
-    where
-        T: Copy,
-    {
-        // SAFETY: it's either this or llvm crashing
-        unsafe { mem::transmute(self) }
-    }
+// permutations
+
+mod shuffle;
+
+pub use shuffle::{simd_shuffle, ShuffleIndex};
+
+// masked ops
+
+mod select;
+
+pub use select::simd_select;
+
+// dispatch
+
+/// Same as `core::intrinsics::const_eval_select`, but with the `scalar` feature, the const
+/// function is called in non-const contexts too, so no platform intrinsics are used.
+macro_rules! const_eval_select {
+    { $arg:expr, $called_in_const:expr, $called_at_runtime:expr $(,)? } => {{
+        #[cfg(not(feature = "scalar"))]
+        let output = ::core::intrinsics::const_eval_select(
+            $arg,
+            $called_in_const,
+            $called_at_runtime,
+        );
+
+        #[cfg(feature = "scalar")]
+        let output = ::core::intrinsics::const_eval_select($arg, $called_in_const, $called_in_const);
+
+        output
+    }};
 }
 
+pub(crate) use const_eval_select;
+
 /*use cake::array;
 
 const fn perform_fold<P, T, const N: usize>(
@@ -19,6 +19,24 @@ where
     Simd::<U, N>::to_array(b)
 }
 
+/// Same as `simd_cast`, but float to integer casts saturate, same as `as`.
+#[inline]
+#[must_use]
+pub unsafe fn simd_as<T, U, const N: usize>(a: [T; N]) -> [U; N]
+where
+    T: Copy,
+    U: Copy,
+{
+    extern "platform-intrinsic" {
+        fn simd_as<T, U>(x: T) -> U;
+    }
+
+    let a = Simd::from_array(a);
+    let b = simd_as(a);
+
+    Simd::<U, N>::to_array(b)
+}
+
 #[inline]
 #[must_use]
 pub unsafe fn simd_cast_mask<T, const N: usize>(a: [T; N]) -> [bool; N]
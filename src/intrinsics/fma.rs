@@ -0,0 +1,20 @@
+use super::Simd;
+use crate::Real;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_fma<T, const N: usize>(a: [T; N], b: [T; N], c: [T; N]) -> [T; N]
+where
+    T: Copy,
+    T: Real,
+{
+    extern "platform-intrinsic" {
+        fn simd_fma<T>(a: T, b: T, c: T) -> T;
+    }
+
+    let a = Simd::from_array(a);
+    let b = Simd::from_array(b);
+    let c = Simd::from_array(c);
+
+    simd_fma(a, b, c).to_array()
+}
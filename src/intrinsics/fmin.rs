@@ -0,0 +1,19 @@
+use super::Simd;
+use crate::Real;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_fmin<T, const N: usize>(a: [T; N], b: [T; N]) -> [T; N]
+where
+    T: Copy,
+    T: Real,
+{
+    extern "platform-intrinsic" {
+        fn simd_fmin<T>(a: T, b: T) -> T;
+    }
+
+    let a = Simd::from_array(a);
+    let b = Simd::from_array(b);
+
+    simd_fmin(a, b).to_array()
+}
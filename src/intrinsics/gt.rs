@@ -1,14 +1,13 @@
 use super::{simd_cast_mask, Simd};
+use crate::vec::Element;
 use core::cmp::PartialOrd;
-use core::ops::BitAnd;
 
 #[inline]
 #[must_use]
 pub unsafe fn simd_gt<T, const N: usize>(a: [T; N], b: [T; N]) -> [bool; N]
 where
-    T: Copy,
+    T: Element,
     T: PartialOrd,
-    T: BitAnd,
 {
     extern "platform-intrinsic" {
         fn simd_gt<T, U>(a: T, b: T) -> U;
@@ -16,7 +15,7 @@ where
 
     let a = Simd::from_array(a);
     let b = Simd::from_array(b);
-    let c = Simd::<T, N>::to_array(simd_gt(a, b));
+    let c = Simd::<T::Mask, N>::to_array(simd_gt(a, b));
 
     simd_cast_mask(c)
 }
@@ -1,14 +1,13 @@
 use super::{simd_cast_mask, Simd};
-use core::cmp::Eq;
-use core::ops::BitAnd;
+use crate::vec::Element;
+use core::cmp::PartialEq;
 
 #[inline]
 #[must_use]
 pub unsafe fn simd_ne<T, const N: usize>(a: [T; N], b: [T; N]) -> [bool; N]
 where
-    T: Copy,
-    T: Eq,
-    T: BitAnd,
+    T: Element,
+    T: PartialEq,
 {
     extern "platform-intrinsic" {
         fn simd_ne<T, U>(a: T, b: T) -> U;
@@ -16,7 +15,7 @@ where
 
     let a = Simd::from_array(a);
     let b = Simd::from_array(b);
-    let c = Simd::<T, N>::to_array(simd_ne(a, b));
+    let c = Simd::<T::Mask, N>::to_array(simd_ne(a, b));
 
     simd_cast_mask(c)
 }
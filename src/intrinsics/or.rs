@@ -0,0 +1,19 @@
+use super::Simd;
+use core::ops::BitOr;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_or<T, const N: usize>(a: [T; N], b: [T; N]) -> [T; N]
+where
+    T: Copy,
+    T: BitOr,
+{
+    extern "platform-intrinsic" {
+        fn simd_or<T>(a: T, b: T) -> T;
+    }
+
+    let a = Simd::from_array(a);
+    let b = Simd::from_array(b);
+
+    simd_or(a, b).to_array()
+}
@@ -0,0 +1,18 @@
+use super::Simd;
+use core::ops::Mul;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_product_unordered<T, const N: usize>(a: [T; N]) -> T
+where
+    T: Copy,
+    T: Mul<Output = T>,
+{
+    extern "platform-intrinsic" {
+        fn simd_reduce_mul_unordered<T, U>(a: T) -> U;
+    }
+
+    let a = Simd::from_array(a);
+
+    simd_reduce_mul_unordered(a)
+}
@@ -0,0 +1,18 @@
+use super::Simd;
+use core::ops::BitAnd;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_reduce_and<T, const N: usize>(a: [T; N]) -> T
+where
+    T: Copy,
+    T: BitAnd<Output = T>,
+{
+    extern "platform-intrinsic" {
+        fn simd_reduce_and<T, U>(a: T) -> U;
+    }
+
+    let a = Simd::from_array(a);
+
+    simd_reduce_and(a)
+}
@@ -0,0 +1,18 @@
+use super::Simd;
+use core::cmp::PartialOrd;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_reduce_min<T, const N: usize>(a: [T; N]) -> T
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    extern "platform-intrinsic" {
+        fn simd_reduce_min<T, U>(a: T) -> U;
+    }
+
+    let a = Simd::from_array(a);
+
+    simd_reduce_min(a)
+}
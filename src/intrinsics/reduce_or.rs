@@ -0,0 +1,18 @@
+use super::Simd;
+use core::ops::BitOr;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_reduce_or<T, const N: usize>(a: [T; N]) -> T
+where
+    T: Copy,
+    T: BitOr<Output = T>,
+{
+    extern "platform-intrinsic" {
+        fn simd_reduce_or<T, U>(a: T) -> U;
+    }
+
+    let a = Simd::from_array(a);
+
+    simd_reduce_or(a)
+}
@@ -0,0 +1,18 @@
+use super::Simd;
+use crate::Real;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_round<T, const N: usize>(a: [T; N]) -> [T; N]
+where
+    T: Copy,
+    T: Real,
+{
+    extern "platform-intrinsic" {
+        fn simd_round<T>(a: T) -> T;
+    }
+
+    let a = Simd::from_array(a);
+
+    simd_round(a).to_array()
+}
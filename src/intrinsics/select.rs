@@ -0,0 +1,23 @@
+use super::{simd_sub, Simd};
+use core::mem;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_select<T, const N: usize>(mask: [bool; N], a: [T; N], b: [T; N]) -> [T; N]
+where
+    T: Copy,
+{
+    extern "platform-intrinsic" {
+        fn simd_select<M, T>(mask: M, a: T, b: T) -> T;
+    }
+
+    // `bool` is either 0 or 1, the intrinsic expects lanes of all zeros or all ones
+    let mask: [i8; N] = mem::transmute_copy(&mask);
+    let mask = simd_sub([0; N], mask);
+
+    let mask = Simd::from_array(mask);
+    let a = Simd::from_array(a);
+    let b = Simd::from_array(b);
+
+    simd_select(mask, a, b).to_array()
+}
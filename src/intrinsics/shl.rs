@@ -0,0 +1,19 @@
+use super::Simd;
+use core::ops::Shl;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_shl<T, const N: usize>(a: [T; N], b: [T; N]) -> [T; N]
+where
+    T: Copy,
+    T: Shl,
+{
+    extern "platform-intrinsic" {
+        fn simd_shl<T>(a: T, b: T) -> T;
+    }
+
+    let a = Simd::from_array(a);
+    let b = Simd::from_array(b);
+
+    simd_shl(a, b).to_array()
+}
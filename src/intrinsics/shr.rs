@@ -0,0 +1,19 @@
+use super::Simd;
+use core::ops::Shr;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_shr<T, const N: usize>(a: [T; N], b: [T; N]) -> [T; N]
+where
+    T: Copy,
+    T: Shr,
+{
+    extern "platform-intrinsic" {
+        fn simd_shr<T>(a: T, b: T) -> T;
+    }
+
+    let a = Simd::from_array(a);
+    let b = Simd::from_array(b);
+
+    simd_shr(a, b).to_array()
+}
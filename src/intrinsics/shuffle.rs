@@ -0,0 +1,48 @@
+use super::Simd;
+use core::marker::PhantomData;
+
+/// Constant shuffle indices.
+///
+/// Lanes are selected from the concatenation of both inputs, where `0..N` are lanes of the first
+/// input, and `N..2 * N` are lanes of the second input.
+pub trait ShuffleIndex<const M: usize> {
+    const INDEX: [usize; M];
+}
+
+/// Shuffle indices, converted to `u32`, as the intrinsic requires a constant.
+struct Index<I, const M: usize>(PhantomData<I>);
+
+impl<I, const M: usize> Index<I, M>
+where
+    I: ShuffleIndex<M>,
+{
+    const INDEX: [u32; M] = {
+        let mut index = [0; M];
+        let mut i = 0;
+
+        while i < M {
+            index[i] = I::INDEX[i] as u32;
+            i += 1;
+        }
+
+        index
+    };
+}
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_shuffle<T, I, const N: usize, const M: usize>(a: [T; N], b: [T; N]) -> [T; M]
+where
+    T: Copy,
+    I: ShuffleIndex<M>,
+{
+    extern "platform-intrinsic" {
+        fn simd_shuffle<T, I, U>(a: T, b: T, index: I) -> U;
+    }
+
+    let a = Simd::from_array(a);
+    let b = Simd::from_array(b);
+    let c: Simd<T, M> = simd_shuffle(a, b, Index::<I, M>::INDEX);
+
+    c.to_array()
+}
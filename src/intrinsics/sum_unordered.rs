@@ -0,0 +1,18 @@
+use super::Simd;
+use core::ops::Add;
+
+#[inline]
+#[must_use]
+pub unsafe fn simd_sum_unordered<T, const N: usize>(a: [T; N]) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+{
+    extern "platform-intrinsic" {
+        fn simd_reduce_add_unordered<T, U>(a: T) -> U;
+    }
+
+    let a = Simd::from_array(a);
+
+    simd_reduce_add_unordered(a)
+}
@@ -1,9 +1,15 @@
 #![allow(incomplete_features)]
 #![deny(warnings)]
+#![feature(adt_const_params)]
+#![feature(array_try_from_fn)]
 #![feature(const_fn_floating_point_arithmetic)]
 #![feature(const_eval_select)]
+#![feature(const_float_bits_conv)]
+#![feature(const_maybe_uninit_as_mut_ptr)]
+#![feature(const_maybe_uninit_assume_init)]
 #![feature(const_mut_refs)]
 #![feature(const_ptr_read)]
+#![feature(const_ptr_write)]
 #![feature(const_refs_to_cell)]
 #![feature(const_slice_index)]
 #![feature(const_trait_impl)]
@@ -12,25 +18,38 @@
 #![feature(generic_const_exprs)]
 #![feature(platform_intrinsics)]
 #![feature(repr_simd)]
+#![cfg_attr(feature = "runtime-dispatch", feature(avx512_target_feature))]
 #![no_std]
 
+#[cfg(feature = "runtime-dispatch")]
+extern crate std;
+
+pub use approx_eq::ApproxEq;
 pub use euler_angles::EulerAngles;
 pub use matrix::Matrix;
+pub use parse::{ParseError, ParseErrorKind};
 pub use quaternion::Quaternion;
 pub use real::Real;
-pub use vec::{Element, LaneCount, Lanes, Vec};
+pub use simd::Simd;
+pub use vec::{Cast, Element, LaneCount, Lanes, Mask, Vec};
 pub use vec2::Vec2;
 pub use vec3::Vec3;
 pub use vec4::Vec4;
 
+mod approx_eq;
+mod display;
 mod euler_angles;
 mod matrix;
+mod parse;
 mod quaternion;
 mod real;
+mod simd;
 mod vec2;
 mod vec3;
 mod vec4;
 
 pub mod identity;
+// unused with the `scalar` feature
+#[cfg_attr(feature = "scalar", allow(dead_code))]
 pub mod intrinsics;
 pub mod vec;
@@ -1,5 +1,8 @@
 use crate::identity::{One, Zero};
-use crate::Vec;
+use crate::{ApproxEq, Element, LaneCount, Lanes, ParseError, Real, Vec};
+use core::fmt;
+use core::ops::{Mul, Sub};
+use core::str::FromStr;
 
 pub struct Matrix<T, const ROWS: usize, const COLUMNS: usize>(Vec<T, { ROWS * COLUMNS }>)
 where
@@ -56,3 +59,100 @@ where
         Self(Vec::one())
     }
 }
+
+/// Element-wise approximate equality, same as `Vec`.
+impl<T, const ROWS: usize, const COLUMNS: usize> const ApproxEq for Matrix<T, ROWS, COLUMNS>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: ~const ApproxEq<Epsilon = T>,
+    T: ~const PartialOrd,
+    T: ~const Sub<Output = T>,
+    T: ~const Mul<Output = T>,
+    Lanes<T, { ROWS * COLUMNS }>: LaneCount,
+    [(); <Lanes<T, { ROWS * COLUMNS }> as LaneCount>::LANES]:,
+    [(); ROWS * COLUMNS]:,
+{
+    type Epsilon = T;
+
+    const DEFAULT_EPSILON: T = <T as ApproxEq>::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T = <T as ApproxEq>::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = <T as ApproxEq>::DEFAULT_MAX_ULPS;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Matrix<T, ROWS, COLUMNS>, epsilon: T) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Matrix<T, ROWS, COLUMNS>,
+        epsilon: T,
+        max_relative: T,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Matrix<T, ROWS, COLUMNS>, epsilon: T, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
+/// Formats as a grid of rows, one row per line, with right-aligned columns, or as
+/// `[[1, 2], [3, 4]]` for `{:#}`.
+///
+/// Precision applies to each element, width is the minimum width of each column.
+impl<T, const ROWS: usize, const COLUMNS: usize> fmt::Display for Matrix<T, ROWS, COLUMNS>
+where
+    T: fmt::Display,
+    [(); ROWS * COLUMNS]:,
+{
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::grid(fmt, &*self.0, COLUMNS)
+    }
+}
+
+/// Parses elements in row-major order, rows may be delimited, such as `1 2 3 4`,
+/// `[[1, 2], [3, 4]]`, or the output of `Display`.
+///
+/// Delimited rows must all have `COLUMNS` elements, and there must be `ROWS` of them.
+///
+/// # Examples
+///
+/// ```
+/// use meth::{Matrix, ParseErrorKind};
+///
+/// assert!("[[1, 2], [3, 4]]".parse::<Matrix<i32, 2, 2>>().is_ok());
+/// assert!("[1, 2]\n[3, 4]".parse::<Matrix<i32, 2, 2>>().is_ok());
+/// assert!("1 2 3".parse::<Matrix<i32, 2, 2>>().is_err());
+///
+/// // ragged rows
+/// let error = "[1, 2, 3], [4]".parse::<Matrix<i32, 2, 2>>().unwrap_err();
+///
+/// assert_eq!(error.kind(), &ParseErrorKind::Trailing);
+///
+/// let error = "[1], [2, 3, 4]".parse::<Matrix<i32, 2, 2>>().unwrap_err();
+///
+/// assert_eq!(error.kind(), &ParseErrorKind::Missing);
+///
+/// // too few, or too many rows
+/// assert!("[[1, 2]]".parse::<Matrix<i32, 2, 2>>().is_err());
+/// assert!("[[1, 2], [3, 4], [5, 6]]".parse::<Matrix<i32, 2, 2>>().is_err());
+/// assert!("[[1, 2], 3, 4]".parse::<Matrix<i32, 2, 2>>().is_err());
+/// ```
+impl<T, const ROWS: usize, const COLUMNS: usize> FromStr for Matrix<T, ROWS, COLUMNS>
+where
+    T: FromStr,
+    [(); ROWS * COLUMNS]:,
+{
+    type Err = ParseError<T::Err>;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Matrix<T, ROWS, COLUMNS>, ParseError<T::Err>> {
+        crate::parse::parse_array(string, Some((ROWS, COLUMNS)))
+            .map(|array| Self(Vec::from_array(array)))
+    }
+}
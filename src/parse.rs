@@ -0,0 +1,261 @@
+use core::fmt;
+use core::str::FromStr;
+
+/// Error when parsing a math type from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError<E> {
+    component: usize,
+    kind: ParseErrorKind<E>,
+}
+
+/// Reason a math type failed to parse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind<E> {
+    /// An opening delimiter without the matching closing delimiter, or vice versa.
+    Delimiter,
+    /// Fewer components than expected.
+    Missing,
+    /// More components than expected.
+    Trailing,
+    /// A component failed to parse.
+    Invalid(E),
+}
+
+impl<E> ParseError<E> {
+    /// Index of the component that failed to parse.
+    #[inline]
+    #[must_use]
+    pub const fn component(&self) -> usize {
+        self.component
+    }
+
+    /// Reason the component failed to parse.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> &ParseErrorKind<E> {
+        &self.kind
+    }
+}
+
+impl<E> fmt::Display for ParseError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let component = self.component;
+
+        match &self.kind {
+            ParseErrorKind::Delimiter => fmt.write_str("unbalanced delimiters"),
+            ParseErrorKind::Missing => write!(fmt, "missing component {component}"),
+            ParseErrorKind::Trailing => write!(fmt, "unexpected component {component}"),
+            ParseErrorKind::Invalid(error) => write!(fmt, "invalid component {component}: {error}"),
+        }
+    }
+}
+
+/// Strips one pair of matching `(` `)`, or `[` `]` delimiters.
+fn strip_delimiters<E>(string: &str) -> Result<&str, ParseError<E>> {
+    let string = string.trim();
+
+    for (open, close) in [('(', ')'), ('[', ']')] {
+        match (string.strip_prefix(open), string.ends_with(close)) {
+            (Some(inner), true) => return Ok(&inner[..inner.len() - close.len_utf8()]),
+            (None, false) => {}
+            _ => {
+                return Err(ParseError {
+                    component: 0,
+                    kind: ParseErrorKind::Delimiter,
+                })
+            }
+        }
+    }
+
+    Ok(string)
+}
+
+/// Checks that `string` is either a flat sequence of components, or `rows` rows of `columns`
+/// components, each row delimited by `(` `)`, or `[` `]`, all at the deepest nesting.
+fn check_rows<E>(string: &str, rows: usize, columns: usize) -> Result<(), ParseError<E>> {
+    let delimiter = ParseError {
+        component: 0,
+        kind: ParseErrorKind::Delimiter,
+    };
+
+    // first pass, the balance, the deepest nesting, and the number of groups that deep
+    let mut depth = 0;
+    let mut deepest = 0;
+    let mut groups = 0;
+
+    // one bit per open delimiter, set for `[`
+    let mut open = 0_u64;
+
+    for c in string.chars() {
+        match c {
+            '(' | '[' => {
+                if depth == u64::BITS {
+                    return Err(delimiter);
+                }
+
+                open = (open << 1) | (c == '[') as u64;
+                depth += 1;
+
+                if depth > deepest {
+                    deepest = depth;
+                    groups = 0;
+                }
+
+                if depth == deepest {
+                    groups += 1;
+                }
+            }
+            ')' | ']' => {
+                if depth == 0 || (open & 1 == 1) != (c == ']') {
+                    return Err(delimiter);
+                }
+
+                open >>= 1;
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err(delimiter);
+    }
+
+    // no rows, such as `1 2 3 4`, or `[1, 2, 3, 4]`
+    if deepest == 0 || (deepest == 1 && groups == 1) {
+        return Ok(());
+    }
+
+    // second pass, the number of components in each row
+    let comma = string.contains(',');
+    let mut row = 0;
+    let mut count = 0;
+    let mut component = false;
+
+    for c in string.chars() {
+        match c {
+            '(' | '[' => {
+                component = false;
+                depth += 1;
+                count = 0;
+            }
+            ')' | ']' => {
+                component = false;
+
+                if depth == deepest {
+                    if row == rows {
+                        return Err(ParseError {
+                            component: rows * columns,
+                            kind: ParseErrorKind::Trailing,
+                        });
+                    }
+
+                    if count < columns {
+                        return Err(ParseError {
+                            component: row * columns + count,
+                            kind: ParseErrorKind::Missing,
+                        });
+                    }
+
+                    if count > columns {
+                        return Err(ParseError {
+                            component: row * columns + columns,
+                            kind: ParseErrorKind::Trailing,
+                        });
+                    }
+
+                    row += 1;
+                }
+
+                depth -= 1;
+            }
+            ',' if comma => component = false,
+            c if c.is_whitespace() => component &= comma,
+            _ => {
+                if !component {
+                    // components outside of a row, such as `[[1, 2], 3]`
+                    if depth != deepest {
+                        return Err(delimiter);
+                    }
+
+                    component = true;
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    if row < rows {
+        return Err(ParseError {
+            component: row * columns,
+            kind: ParseErrorKind::Missing,
+        });
+    }
+
+    Ok(())
+}
+
+/// Parses `N` components, separated by commas, or whitespace, optionally enclosed in `(` `)`,
+/// or `[` `]`.
+///
+/// With `shape`, `(rows, columns)`, components may also be grouped in delimited rows, such as
+/// `[[1, 2], [3, 4]]`, which must all have `columns` components, and there must be `rows` of
+/// them.
+pub(crate) fn parse_array<T, const N: usize>(
+    string: &str,
+    shape: Option<(usize, usize)>,
+) -> Result<[T; N], ParseError<T::Err>>
+where
+    T: FromStr,
+{
+    let nested = shape.is_some();
+    let string = match shape {
+        Some((rows, columns)) => {
+            check_rows(string, rows, columns)?;
+            string.trim()
+        }
+        None => strip_delimiters(string)?,
+    };
+
+    let comma = string.contains(',');
+
+    if !nested && string.contains(['(', ')', '[', ']']) {
+        return Err(ParseError {
+            component: 0,
+            kind: ParseErrorKind::Delimiter,
+        });
+    }
+
+    let mut components = string
+        .split(|c: char| {
+            (if comma { c == ',' } else { c.is_whitespace() })
+                || (nested && matches!(c, '(' | ')' | '[' | ']'))
+        })
+        .map(str::trim)
+        .filter(|component| !(component.is_empty() && (nested || !comma)))
+        .fuse();
+
+    let array = core::array::try_from_fn(|component| {
+        let string = components.next().ok_or(ParseError {
+            component,
+            kind: ParseErrorKind::Missing,
+        })?;
+
+        string.parse().map_err(|error| ParseError {
+            component,
+            kind: ParseErrorKind::Invalid(error),
+        })
+    })?;
+
+    if components.next().is_some() {
+        return Err(ParseError {
+            component: N,
+            kind: ParseErrorKind::Trailing,
+        });
+    }
+
+    Ok(array)
+}
@@ -1,5 +1,10 @@
 use crate::identity::{One, Zero};
-use crate::{EulerAngles, Real};
+use crate::{ApproxEq, Element, EulerAngles, LaneCount, Lanes, ParseError, Real, Vec};
+use core::cmp::{Eq, PartialEq};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Mul, Neg, Sub};
+use core::str::FromStr;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Quaternion<T> {
@@ -51,6 +56,190 @@ impl<T> Quaternion<T> {
     }
 }
 
+impl<T> const PartialEq for Quaternion<T>
+where
+    T: ~const PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Quaternion<T>) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+impl<T> Eq for Quaternion<T> where T: Eq {}
+
+impl<T> Hash for Quaternion<T>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+        self.w.hash(state);
+    }
+}
+
+impl<T> const Default for Quaternion<T>
+where
+    T: ~const Zero,
+{
+    /// Creates a new quaternion with all elements set to zero.
+    #[inline]
+    fn default() -> Quaternion<T> {
+        Self::zero()
+    }
+}
+
+/// Approximate equality of rotations.
+impl<T> Quaternion<T>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: ~const ApproxEq<Epsilon = T>,
+    T: ~const PartialOrd,
+    T: ~const Neg<Output = T>,
+    T: ~const Sub<Output = T>,
+    T: ~const Mul<Output = T>,
+    Lanes<T, 4>: LaneCount,
+    [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+{
+    #[inline]
+    const fn to_vec(self) -> Vec<T, 4> {
+        Vec::from_array([self.x, self.y, self.z, self.w])
+    }
+
+    #[inline]
+    const fn to_neg_vec(self) -> Vec<T, 4> {
+        Vec::from_array([-self.x, -self.y, -self.z, -self.w])
+    }
+
+    /// Same as [`ApproxEq::abs_diff_eq`], but `other` and `-other` are treated as equal, as they
+    /// represent the same rotation.
+    #[inline]
+    #[must_use]
+    pub const fn abs_diff_eq_rotation(&self, other: &Quaternion<T>, epsilon: T) -> bool {
+        let a = self.to_vec();
+
+        a.abs_diff_eq(&other.to_vec(), epsilon) || a.abs_diff_eq(&other.to_neg_vec(), epsilon)
+    }
+
+    /// Same as [`ApproxEq::relative_eq`], but `other` and `-other` are treated as equal, as they
+    /// represent the same rotation.
+    #[inline]
+    #[must_use]
+    pub const fn relative_eq_rotation(
+        &self,
+        other: &Quaternion<T>,
+        epsilon: T,
+        max_relative: T,
+    ) -> bool {
+        let a = self.to_vec();
+
+        a.relative_eq(&other.to_vec(), epsilon, max_relative)
+            || a.relative_eq(&other.to_neg_vec(), epsilon, max_relative)
+    }
+
+    /// Same as [`ApproxEq::ulps_eq`], but `other` and `-other` are treated as equal, as they
+    /// represent the same rotation.
+    #[inline]
+    #[must_use]
+    pub const fn ulps_eq_rotation(&self, other: &Quaternion<T>, epsilon: T, max_ulps: u32) -> bool {
+        let a = self.to_vec();
+
+        a.ulps_eq(&other.to_vec(), epsilon, max_ulps)
+            || a.ulps_eq(&other.to_neg_vec(), epsilon, max_ulps)
+    }
+}
+
+/// Component-wise approximate equality, use the `*_rotation` methods to treat `q` and `-q` as
+/// equal.
+impl<T> const ApproxEq for Quaternion<T>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: ~const ApproxEq<Epsilon = T>,
+    T: ~const PartialOrd,
+    T: ~const Neg<Output = T>,
+    T: ~const Sub<Output = T>,
+    T: ~const Mul<Output = T>,
+    Lanes<T, 4>: LaneCount,
+    [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+{
+    type Epsilon = T;
+
+    const DEFAULT_EPSILON: T = <T as ApproxEq>::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T = <T as ApproxEq>::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = <T as ApproxEq>::DEFAULT_MAX_ULPS;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Quaternion<T>, epsilon: T) -> bool {
+        self.to_vec().abs_diff_eq(&other.to_vec(), epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Quaternion<T>, epsilon: T, max_relative: T) -> bool {
+        self.to_vec().relative_eq(&other.to_vec(), epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Quaternion<T>, epsilon: T, max_ulps: u32) -> bool {
+        self.to_vec().ulps_eq(&other.to_vec(), epsilon, max_ulps)
+    }
+}
+
+/// Formats as `(x, y, z, w)`, or `w+xi+yj+zk` for `{:#}`.
+///
+/// Precision applies to each component, width only applies to the first form.
+///
+/// # Examples
+///
+/// ```
+/// use meth::Quaternion;
+///
+/// let quaternion = Quaternion::from_xyzw(0.5, -1.0, 0.0, 1.0);
+///
+/// assert_eq!(format!("{quaternion:.1}"), "(0.5, -1.0, 0.0, 1.0)");
+/// assert_eq!(format!("{quaternion:#.1}"), "1.0+0.5i-1.0j+0.0k");
+/// ```
+impl<T> fmt::Display for Quaternion<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if !fmt.alternate() {
+            return crate::display::tuple(fmt, &[&self.x, &self.y, &self.z, &self.w]);
+        }
+
+        let options = crate::display::Options::new(fmt);
+        let imaginary = options.with_sign();
+
+        options.write(fmt, &self.w)?;
+        imaginary.write(fmt, &self.x)?;
+        fmt.write_str("i")?;
+        imaginary.write(fmt, &self.y)?;
+        fmt.write_str("j")?;
+        imaginary.write(fmt, &self.z)?;
+        fmt.write_str("k")
+    }
+}
+
+/// Parses `x`, `y`, `z`, and `w` the same as `Vec`, such as `0 0 0 1`, or `(0, 0, 0, 1)`.
+impl<T> FromStr for Quaternion<T>
+where
+    T: FromStr,
+{
+    type Err = ParseError<T::Err>;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Quaternion<T>, ParseError<T::Err>> {
+        let [x, y, z, w] = crate::parse::parse_array(string, None)?;
+
+        Ok(Self::from_xyzw(x, y, z, w))
+    }
+}
+
 // @note: https://wikiless.org/wiki/Conversion_between_quaternions_and_Euler_angles
 impl const From<EulerAngles<f32>> for Quaternion<f32> {
     fn from(angles: EulerAngles<f32>) -> Self {
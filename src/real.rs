@@ -1,7 +1,87 @@
+use core::cmp::Ordering;
+use core::intrinsics::const_eval_select;
+use core::num::FpCategory;
+
 pub(crate) use sealed::Sealed;
 
+mod fast;
 mod sealed;
-
+mod soft;
+
+/// Floating point operations, usable in const contexts.
+///
+/// # Const evaluation
+///
+/// In const contexts, [`sqrt`](Self::sqrt), [`sin`](Self::sin), [`cos`](Self::cos),
+/// [`sin_cos`](Self::sin_cos), [`atan`](Self::atan), [`atan2`](Self::atan2), [`exp`](Self::exp),
+/// and [`ln`](Self::ln) are evaluated by the crate itself, so they do not depend on `libm` being
+/// const. `sqrt` is correctly rounded, the rest are within one ulp, and may differ from the
+/// runtime results in the last bit.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(const_trait_impl)]
+///
+/// use meth::Real;
+///
+/// const TABLE: [f32; 8] = {
+///     let mut table = [0.0; 8];
+///     let mut i = 0;
+///
+///     while i < 8 {
+///         table[i] = Real::sin(i as f32 * 0.8);
+///         i += 1;
+///     }
+///
+///     table
+/// };
+///
+/// for (i, sin) in TABLE.into_iter().enumerate() {
+///     let x = i as f32 * 0.8;
+///
+///     assert!((sin - Real::sin(x)).abs() <= f32::EPSILON);
+/// }
+///
+/// const ROOT: f64 = Real::sqrt(2.0);
+/// const ANGLE: f64 = Real::atan2(1.0, -1.0);
+/// const E: f64 = Real::exp(1.0);
+///
+/// assert_eq!(ROOT, 2.0_f64.sqrt());
+/// assert!((ANGLE - 1.0_f64.atan2(-1.0)).abs() <= f64::EPSILON);
+/// assert!((Real::ln(E) - 1.0).abs() <= f64::EPSILON);
+/// ```
+///
+/// Large arguments of `sin`, and `cos` are reduced exactly, and subnormal arguments, and
+/// results of `exp`, and `ln` are handled, with the same one ulp bound against the runtime
+/// results:
+///
+/// ```
+/// #![feature(const_trait_impl)]
+///
+/// use meth::{ApproxEq, Real};
+///
+/// const SIN_1E22: f64 = Real::sin(1e22);
+/// const COS_1E22: f64 = Real::cos(1e22);
+/// const SIN_MAX: f64 = Real::sin(f64::MAX);
+/// const COS_MAX: f64 = Real::cos(f64::MAX);
+/// const SQRT_MAX: f64 = Real::sqrt(f64::MAX);
+/// const SQRT_SUBNORMAL: f64 = Real::sqrt(1e-310);
+/// const EXP_SUBNORMAL: f64 = Real::exp(-740.0);
+/// const LN_SUBNORMAL: f64 = Real::ln(1e-310);
+/// const LN_MIN_POSITIVE: f64 = Real::ln(5e-324);
+///
+/// assert!(SIN_1E22.ulps_eq(&Real::sin(1e22), 0.0, 1));
+/// assert!(COS_1E22.ulps_eq(&Real::cos(1e22), 0.0, 1));
+/// assert!(SIN_MAX.ulps_eq(&Real::sin(f64::MAX), 0.0, 1));
+/// assert!(COS_MAX.ulps_eq(&Real::cos(f64::MAX), 0.0, 1));
+/// assert_eq!(SQRT_MAX, Real::sqrt(f64::MAX));
+/// assert_eq!(SQRT_SUBNORMAL, Real::sqrt(1e-310));
+/// assert!(EXP_SUBNORMAL.is_subnormal());
+/// assert!(EXP_SUBNORMAL.ulps_eq(&Real::exp(-740.0), 0.0, 1));
+/// assert!(LN_SUBNORMAL.ulps_eq(&Real::ln(1e-310), 0.0, 1));
+/// assert!(LN_MIN_POSITIVE.ulps_eq(&Real::ln(5e-324), 0.0, 1));
+/// ```
 pub trait Real: Sealed {
     const DIGITS: u32;
     const EPSILON: Self;
@@ -95,6 +175,38 @@ pub trait Real: Sealed {
     #[must_use]
     fn abs(self) -> Self;
 
+    /// Computes the arccosine of a number. Return value is in radians in
+    /// the range [0, pi] or NaN if the number is outside the range
+    /// [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = std::f32::consts::FRAC_PI_4;
+    ///
+    /// // acos(cos(pi/4))
+    /// let abs_difference = (f.cos().acos() - std::f32::consts::FRAC_PI_4).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn acos(self) -> Self;
+
+    /// Inverse hyperbolic cosine function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 1.0f32;
+    /// let f = x.cosh().acosh();
+    ///
+    /// let abs_difference = (f - x).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn acosh(self) -> Self;
+
     /// Computes the arcsine of a number. Return value is in radians in
     /// the range [-pi/2, pi/2] or NaN if the number is outside the range
     /// [-1, 1].
@@ -112,6 +224,37 @@ pub trait Real: Sealed {
     #[must_use]
     fn asin(self) -> Self;
 
+    /// Inverse hyperbolic sine function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 1.0f32;
+    /// let f = x.sinh().asinh();
+    ///
+    /// let abs_difference = (f - x).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn asinh(self) -> Self;
+
+    /// Computes the arctangent of a number. Return value is in radians in the
+    /// range [-pi/2, pi/2].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 1.0f32;
+    ///
+    /// // atan(tan(1))
+    /// let abs_difference = (f.tan().atan() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn atan(self) -> Self;
+
     /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
     ///
     /// * `x = 0`, `y = 0`: `0`
@@ -139,6 +282,50 @@ pub trait Real: Sealed {
     #[must_use]
     fn atan2(self, other: Self) -> Self;
 
+    /// Inverse hyperbolic tangent function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let e = std::f32::consts::E;
+    /// let f = e.tanh().atanh();
+    ///
+    /// let abs_difference = (f - e).abs();
+    ///
+    /// assert!(abs_difference <= 1e-5);
+    /// ```
+    #[must_use]
+    fn atanh(self) -> Self;
+
+    /// Returns the cube root of a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 8.0f32;
+    ///
+    /// // x^(1/3) - 2 == 0
+    /// let abs_difference = (x.cbrt() - 2.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn cbrt(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 3.01_f32;
+    /// let g = 4.0_f32;
+    ///
+    /// assert_eq!(f.ceil(), 4.0);
+    /// assert_eq!(g.ceil(), 4.0);
+    /// ```
+    #[must_use]
+    fn ceil(self) -> Self;
+
     /// Restrict a value to a certain interval unless it is NaN.
     ///
     /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
@@ -162,6 +349,24 @@ pub trait Real: Sealed {
     #[must_use]
     fn clamp(self, min: Self, max: Self) -> Self;
 
+    /// Returns the floating point category of the number. If only one property
+    /// is going to be tested, it is generally faster to use the specific
+    /// predicate instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::FpCategory;
+    ///
+    /// let num = 12.4_f32;
+    /// let inf = f32::INFINITY;
+    ///
+    /// assert_eq!(num.classify(), FpCategory::Normal);
+    /// assert_eq!(inf.classify(), FpCategory::Infinite);
+    /// ```
+    #[must_use]
+    fn classify(self) -> FpCategory;
+
     /// Returns a number composed of the magnitude of `self` and the sign of
     /// `sign`.
     ///
@@ -198,180 +403,1130 @@ pub trait Real: Sealed {
     #[must_use]
     fn cos(self) -> Self;
 
-    /// Returns the maximum of the two numbers.
+    /// Hyperbolic cosine function.
     ///
-    /// Follows the IEEE-754 2008 semantics for maxNum, except for handling of signaling NaNs.
-    /// This matches the behavior of libm’s fmin.
+    /// # Examples
     ///
     /// ```
+    /// let e = std::f32::consts::E;
     /// let x = 1.0f32;
-    /// let y = 2.0f32;
+    /// let f = x.cosh();
     ///
-    /// assert_eq!(x.max(y), y);
-    /// ```
+    /// // Solving cosh() at 1 gives this result
+    /// let g = ((e * e) + 1.0) / (2.0 * e);
+    /// let abs_difference = (f - g).abs();
     ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// // Same result
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
     #[must_use]
-    fn max(self, other: Self) -> Self;
+    fn cosh(self) -> Self;
 
-    /// Returns the minimum of the two numbers.
+    /// Calculates Euclidean division, the matching method for `rem_euclid`.
     ///
-    /// Follows the IEEE-754 2008 semantics for minNum, except for handling of signaling NaNs.
-    /// This matches the behavior of libm’s fmin.
+    /// This computes the integer `n` such that
+    /// `self = n * rhs + self.rem_euclid(rhs)`.
+    /// In other words, the result is `self / rhs` rounded to the integer `n`
+    /// such that `self >= n * rhs`.
     ///
-    /// ```
-    /// let x = 1.0f32;
-    /// let y = 2.0f32;
+    /// # Examples
     ///
-    /// assert_eq!(x.min(y), x);
     /// ```
+    /// let a: f32 = 7.0;
+    /// let b = 4.0;
     ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// assert_eq!(a.div_euclid(b), 1.0); // 7.0 > 4.0 * 1.0
+    /// assert_eq!((-a).div_euclid(b), -2.0); // -7.0 >= 4.0 * -2.0
+    /// assert_eq!(a.div_euclid(-b), -1.0); // 7.0 >= -4.0 * -1.0
+    /// assert_eq!((-a).div_euclid(-b), 2.0); // -7.0 >= -4.0 * 2.0
+    /// ```
     #[must_use]
-    fn min(self, other: Self) -> Self;
+    fn div_euclid(self, rhs: Self) -> Self;
 
-    /// Computes the sine of a number (in radians).
+    /// Returns `e^(self)`, (the exponential function).
     ///
     /// # Examples
     ///
     /// ```
-    /// let x = std::f32::consts::FRAC_PI_2;
+    /// let one = 1.0f32;
+    /// // e^1
+    /// let e = one.exp();
     ///
-    /// let abs_difference = (x.sin() - 1.0).abs();
+    /// // ln(e) - 1 == 0
+    /// let abs_difference = (e.ln() - 1.0).abs();
     ///
     /// assert!(abs_difference <= f32::EPSILON);
     /// ```
     #[must_use]
-    fn sin(self) -> Self;
+    fn exp(self) -> Self;
 
-    /// Simultaneously computes the sine and cosine of the number, `x`. Returns
-    /// `(sin(x), cos(x))`.
+    /// Returns `2^(self)`.
     ///
     /// # Examples
     ///
     /// ```
-    /// let x = std::f32::consts::FRAC_PI_4;
-    /// let f = x.sin_cos();
+    /// let f = 2.0f32;
     ///
-    /// let abs_difference_0 = (f.0 - x.sin()).abs();
-    /// let abs_difference_1 = (f.1 - x.cos()).abs();
+    /// // 2^2 - 4 == 0
+    /// let abs_difference = (f.exp2() - 4.0).abs();
     ///
-    /// assert!(abs_difference_0 <= f32::EPSILON);
-    /// assert!(abs_difference_1 <= f32::EPSILON);
+    /// assert!(abs_difference <= f32::EPSILON);
     /// ```
     #[must_use]
-    fn sin_cos(self) -> (Self, Self);
-
-    fn sqrt(self) -> Self;
+    fn exp2(self) -> Self;
 
-    /// Converts radians to degrees.
+    /// Returns `e^(self) - 1` in a way that is accurate even if the
+    /// number is close to zero.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// let angle = std::f32::consts::PI;
+    /// let x = 1e-8_f32;
     ///
-    /// let abs_difference = (angle.to_degrees() - 180.0).abs();
+    /// // for very small x, e^x is approximately 1 + x + x^2 / 2
+    /// let approx = x + x * x / 2.0;
+    /// let abs_difference = (x.exp_m1() - approx).abs();
     ///
-    /// assert!(abs_difference <= f32::EPSILON);
+    /// assert!(abs_difference < 1e-10);
     /// ```
     #[must_use]
-    fn to_degrees(self) -> Self;
+    fn exp_m1(self) -> Self;
 
-    /// Converts degrees to radians.
+    /// Fast approximation of [`atan2`](Self::atan2).
+    ///
+    /// The maximum absolute error is `2e-5` radians.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// let angle = 180.0f32;
+    /// use meth::Real;
     ///
-    /// let abs_difference = (angle.to_radians() - std::f32::consts::PI).abs();
+    /// for i in 0..1000 {
+    ///     let angle = i as f32 * 0.0063 - 3.14;
+    ///     let (y, x) = (angle.sin() * 10.0, angle.cos() * 10.0);
     ///
-    /// assert!(abs_difference <= f32::EPSILON);
+    ///     assert!((Real::fast_atan2(y, x) - y.atan2(x)).abs() < 2e-5);
+    /// }
     /// ```
     #[must_use]
-    fn to_radians(self) -> Self;
-}
-
-impl const Real for f32 {
-    const DIGITS: u32 = core::f32::DIGITS;
-    const EPSILON: f32 = core::f32::EPSILON;
-    const INFINITY: f32 = core::f32::INFINITY;
-    const MANTISSA_DIGITS: u32 = core::f32::MANTISSA_DIGITS;
-    const MAX: f32 = core::f32::MAX;
-    const MAX_10_EXP: i32 = core::f32::MAX_10_EXP;
-    const MAX_EXP: i32 = core::f32::MAX_EXP;
-    const MIN: f32 = core::f32::MIN;
-    const MIN_10_EXP: i32 = core::f32::MIN_10_EXP;
-    const MIN_EXP: i32 = core::f32::MIN_EXP;
-    const NAN: f32 = core::f32::NAN;
-    const NEG_INFINITY: f32 = core::f32::NEG_INFINITY;
-
-    const E: f32 = ::core::f32::consts::E;
-    const FRAC_1_PI: f32 = ::core::f32::consts::FRAC_1_PI;
-    const FRAC_1_SQRT_2: f32 = ::core::f32::consts::FRAC_1_SQRT_2;
-    const FRAC_2_PI: f32 = ::core::f32::consts::FRAC_2_PI;
-    const FRAC_2_SQRT_PI: f32 = ::core::f32::consts::FRAC_2_SQRT_PI;
-    const FRAC_PI_2: f32 = ::core::f32::consts::FRAC_PI_2;
-    const FRAC_PI_3: f32 = ::core::f32::consts::FRAC_PI_3;
-    const FRAC_PI_4: f32 = ::core::f32::consts::FRAC_PI_4;
-    const FRAC_PI_6: f32 = ::core::f32::consts::FRAC_PI_6;
-    const FRAC_PI_8: f32 = ::core::f32::consts::FRAC_PI_8;
-    const LN_2: f32 = ::core::f32::consts::LN_2;
-    const LN_10: f32 = ::core::f32::consts::LN_10;
-    const LOG2_10: f32 = ::core::f32::consts::LOG2_10;
-    const LOG2_E: f32 = ::core::f32::consts::LOG2_E;
-    const LOG10_2: f32 = ::core::f32::consts::LOG10_2;
-    const LOG10_E: f32 = ::core::f32::consts::LOG10_E;
-    const PI: f32 = <Self as Sealed>::_PI;
-    const SQRT_2: f32 = ::core::f32::consts::SQRT_2;
-    const TAU: f32 = ::core::f32::consts::TAU;
+    fn fast_atan2(self, other: Self) -> Self;
 
+    /// Fast approximation of [`cos`](Self::cos).
+    ///
+    /// The maximum absolute error is `1.5e-6` for `|x| <= 1000`, and grows with `|x|` beyond
+    /// that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// for i in 0..=200_000 {
+    ///     let x = i as f32 * 0.01 - 1000.0;
+    ///
+    ///     assert!((Real::fast_cos(x) - x.cos()).abs() < 1.5e-6);
+    /// }
+    /// ```
     #[must_use]
-    #[inline]
-    fn abs(self) -> f32 {
-        libm::fabsf(self)
-    }
+    fn fast_cos(self) -> Self;
 
+    /// Fast approximation of [`exp`](Self::exp).
+    ///
+    /// The maximum relative error is `8e-6`. Results below the smallest normal number are
+    /// flushed to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// for i in 0..1000 {
+    ///     let x = i as f32 * 0.17 - 85.0;
+    ///
+    ///     assert!((Real::fast_exp(x) / x.exp() - 1.0).abs() < 8e-6);
+    /// }
+    /// ```
     #[must_use]
-    #[inline]
-    fn asin(self) -> f32 {
-        libm::asinf(self)
-    }
+    fn fast_exp(self) -> Self;
 
+    /// Fast approximation of [`recip`](Self::recip).
+    ///
+    /// The maximum relative error is `7e-6`, for normal numbers with a normal reciprocal.
+    ///
+    /// Zero and subnormal numbers return infinity, and infinity, and numbers with a subnormal
+    /// reciprocal return zero, both with the sign of `self`. NaN returns NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// for i in 0..2500 {
+    ///     let x = (i as f32 * 0.1 - 125.0).exp2();
+    ///
+    ///     assert!((Real::fast_recip(x) * x - 1.0).abs() < 7e-6);
+    ///     assert!((Real::fast_recip(-x) * -x - 1.0).abs() < 7e-6);
+    /// }
+    ///
+    /// assert_eq!(Real::fast_recip(0.0_f32), f32::INFINITY);
+    /// assert_eq!(Real::fast_recip(-0.0_f32), f32::NEG_INFINITY);
+    /// assert_eq!(Real::fast_recip(1e-40_f32), f32::INFINITY);
+    /// assert_eq!(Real::fast_recip(f32::INFINITY), 0.0);
+    /// assert_eq!(Real::fast_recip(f32::NEG_INFINITY), -0.0);
+    /// assert_eq!(Real::fast_recip(f32::MAX), 0.0);
+    /// assert!(Real::fast_recip(f32::NAN).is_nan());
+    /// ```
     #[must_use]
-    #[inline]
-    fn atan2(self, other: f32) -> f32 {
-        libm::atan2f(self, other)
-    }
+    fn fast_recip(self) -> Self;
 
+    /// Fast approximation of the reciprocal square root, `1 / sqrt(x)`.
+    ///
+    /// The maximum relative error is `5e-6`, for positive normal numbers.
+    ///
+    /// Negative numbers and NaN return NaN, zero and subnormal numbers return infinity with the
+    /// sign of `self`, and infinity returns zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// for i in 0..2540 {
+    ///     let x = (i as f32 * 0.1 - 126.0).exp2();
+    ///
+    ///     assert!((Real::fast_rsqrt(x) * x.sqrt() - 1.0).abs() < 5e-6);
+    /// }
+    ///
+    /// assert!((Real::fast_rsqrt(f32::MAX) * f32::MAX.sqrt() - 1.0).abs() < 5e-6);
+    /// assert_eq!(Real::fast_rsqrt(0.0_f32), f32::INFINITY);
+    /// assert_eq!(Real::fast_rsqrt(-0.0_f32), f32::NEG_INFINITY);
+    /// assert_eq!(Real::fast_rsqrt(1e-40_f32), f32::INFINITY);
+    /// assert_eq!(Real::fast_rsqrt(f32::INFINITY), 0.0);
+    /// assert!(Real::fast_rsqrt(-1.0_f32).is_nan());
+    /// assert!(Real::fast_rsqrt(f32::NEG_INFINITY).is_nan());
+    /// assert!(Real::fast_rsqrt(f32::NAN).is_nan());
+    /// ```
     #[must_use]
-    #[inline]
-    fn clamp(self, min: f32, max: f32) -> f32 {
-        let mut x = self;
-
-        if x < min {
-            x = min;
-        }
-
-        if x > max {
-            x = max;
-        }
-
-        x
-    }
+    fn fast_rsqrt(self) -> Self;
 
+    /// Fast approximation of [`sin`](Self::sin).
+    ///
+    /// The maximum absolute error is `1.5e-6` for `|x| <= 1000`, and grows with `|x|` beyond
+    /// that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// for i in 0..=200_000 {
+    ///     let x = i as f32 * 0.01 - 1000.0;
+    ///
+    ///     assert!((Real::fast_sin(x) - x.sin()).abs() < 1.5e-6);
+    /// }
+    /// ```
     #[must_use]
-    #[inline]
-    fn copysign(self, sign: f32) -> f32 {
-        libm::copysignf(self, sign)
-    }
+    fn fast_sin(self) -> Self;
 
+    /// Fast approximation of [`sin_cos`](Self::sin_cos).
+    ///
+    /// Same accuracy as [`fast_sin`](Self::fast_sin), and [`fast_cos`](Self::fast_cos).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// let (sin, cos) = Real::fast_sin_cos(1.0_f32);
+    ///
+    /// assert!((sin - 1.0_f32.sin()).abs() < 1.5e-6);
+    /// assert!((cos - 1.0_f32.cos()).abs() < 1.5e-6);
+    /// ```
     #[must_use]
-    #[inline]
-    fn cos(self) -> f32 {
-        libm::cosf(self)
-    }
+    fn fast_sin_cos(self) -> (Self, Self);
 
-    #[must_use]
-    #[inline]
-    fn max(self, other: f32) -> f32 {
+    /// Returns the largest integer less than or equal to a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 3.7_f32;
+    /// let g = 3.0_f32;
+    /// let h = -3.7_f32;
+    ///
+    /// assert_eq!(f.floor(), 3.0);
+    /// assert_eq!(g.floor(), 3.0);
+    /// assert_eq!(h.floor(), -4.0);
+    /// ```
+    #[must_use]
+    fn floor(self) -> Self;
+
+    /// Returns the fractional part of a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 3.6_f32;
+    /// let y = -3.6_f32;
+    /// let abs_difference_x = (x.fract() - 0.6).abs();
+    /// let abs_difference_y = (y.fract() - (-0.6)).abs();
+    ///
+    /// assert!(abs_difference_x <= 1e-6);
+    /// assert!(abs_difference_y <= 1e-6);
+    /// ```
+    #[must_use]
+    fn fract(self) -> Self;
+
+    /// Computes the length of the hypotenuse of a right-angle triangle given
+    /// legs of length `x` and `y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 2.0f32;
+    /// let y = 3.0f32;
+    ///
+    /// // sqrt(x^2 + y^2)
+    /// let abs_difference = (x.hypot(y) - (x.powi(2) + y.powi(2)).sqrt()).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn hypot(self, other: Self) -> Self;
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 7.0f32;
+    /// let inf = f32::INFINITY;
+    /// let neg_inf = f32::NEG_INFINITY;
+    /// let nan = f32::NAN;
+    ///
+    /// assert!(f.is_finite());
+    ///
+    /// assert!(!nan.is_finite());
+    /// assert!(!inf.is_finite());
+    /// assert!(!neg_inf.is_finite());
+    /// ```
+    #[must_use]
+    fn is_finite(self) -> bool;
+
+    /// Returns `true` if this value is positive infinity or negative infinity, and
+    /// `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 7.0f32;
+    /// let inf = f32::INFINITY;
+    /// let neg_inf = f32::NEG_INFINITY;
+    /// let nan = f32::NAN;
+    ///
+    /// assert!(!f.is_infinite());
+    /// assert!(!nan.is_infinite());
+    ///
+    /// assert!(inf.is_infinite());
+    /// assert!(neg_inf.is_infinite());
+    /// ```
+    #[must_use]
+    fn is_infinite(self) -> bool;
+
+    /// Returns `true` if this value is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let nan = f32::NAN;
+    /// let f = 7.0_f32;
+    ///
+    /// assert!(nan.is_nan());
+    /// assert!(!f.is_nan());
+    /// ```
+    #[must_use]
+    fn is_nan(self) -> bool;
+
+    /// Returns `true` if the number is neither zero, infinite,
+    /// [subnormal], or NaN.
+    ///
+    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let min = f32::MIN_POSITIVE; // 1.17549435e-38f32
+    /// let max = f32::MAX;
+    /// let lower_than_min = 1.0e-40_f32;
+    /// let zero = 0.0_f32;
+    ///
+    /// assert!(min.is_normal());
+    /// assert!(max.is_normal());
+    ///
+    /// assert!(!zero.is_normal());
+    /// assert!(!f32::NAN.is_normal());
+    /// assert!(!f32::INFINITY.is_normal());
+    /// // Values between `0` and `min` are Subnormal.
+    /// assert!(!lower_than_min.is_normal());
+    /// ```
+    #[must_use]
+    fn is_normal(self) -> bool;
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`, NaNs with
+    /// negative sign bit and negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 7.0_f32;
+    /// let g = -7.0_f32;
+    ///
+    /// assert!(!f.is_sign_negative());
+    /// assert!(g.is_sign_negative());
+    /// ```
+    #[must_use]
+    fn is_sign_negative(self) -> bool;
+
+    /// Returns the natural logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let one = 1.0f32;
+    /// // e^1
+    /// let e = one.exp();
+    ///
+    /// // ln(e) - 1 == 0
+    /// let abs_difference = (e.ln() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn ln(self) -> Self;
+
+    /// Returns `ln(1+n)` (natural logarithm) more accurately than if
+    /// the operations were performed separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 1e-8_f32;
+    ///
+    /// // for very small x, ln(1 + x) is approximately x - x^2 / 2
+    /// let approx = x - x * x / 2.0;
+    /// let abs_difference = (x.ln_1p() - approx).abs();
+    ///
+    /// assert!(abs_difference < 1e-10);
+    /// ```
+    #[must_use]
+    fn ln_1p(self) -> Self;
+
+    /// Returns the logarithm of the number with respect to an arbitrary base.
+    ///
+    /// The result might not be correctly rounded owing to implementation details;
+    /// `self.log2()` can produce more accurate results for base 2, and
+    /// `self.log10()` can produce more accurate results for base 10.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let five = 5.0f32;
+    ///
+    /// // log5(5) - 1 == 0
+    /// let abs_difference = (five.log(5.0) - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn log(self, base: Self) -> Self;
+
+    /// Returns the base 10 logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let hundred = 100.0f32;
+    ///
+    /// // log10(100) - 2 == 0
+    /// let abs_difference = (hundred.log10() - 2.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn log10(self) -> Self;
+
+    /// Returns the base 2 logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let two = 2.0f32;
+    ///
+    /// // log2(2) - 1 == 0
+    /// let abs_difference = (two.log2() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn log2(self) -> Self;
+
+    /// Returns the maximum of the two numbers.
+    ///
+    /// Follows the IEEE-754 2008 semantics for maxNum, except for handling of signaling NaNs.
+    /// This matches the behavior of libm’s fmin.
+    ///
+    /// ```
+    /// let x = 1.0f32;
+    /// let y = 2.0f32;
+    ///
+    /// assert_eq!(x.max(y), y);
+    /// ```
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    #[must_use]
+    fn max(self, other: Self) -> Self;
+
+    /// Returns the minimum of the two numbers.
+    ///
+    /// Follows the IEEE-754 2008 semantics for minNum, except for handling of signaling NaNs.
+    /// This matches the behavior of libm’s fmin.
+    ///
+    /// ```
+    /// let x = 1.0f32;
+    /// let y = 2.0f32;
+    ///
+    /// assert_eq!(x.min(y), x);
+    /// ```
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    #[must_use]
+    fn min(self, other: Self) -> Self;
+
+    /// Fused multiply-add. Computes `(self * a) + b` with only one rounding
+    /// error, yielding a more accurate result than an unfused multiply-add.
+    ///
+    /// The result is the same in const, and non-const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let m = 10.0_f32;
+    /// let x = 4.0_f32;
+    /// let b = 60.0_f32;
+    ///
+    /// // 100.0
+    /// let abs_difference = (m.mul_add(x, b) - ((m * x) + b)).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Raises a number to a floating point power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 2.0f32;
+    /// let abs_difference = (x.powf(2.0) - (x * x)).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn powf(self, n: Self) -> Self;
+
+    /// Raises a number to an integer power.
+    ///
+    /// The result is the same as `powf` with `n` converted to a float. For `f32`, it's computed
+    /// with `f64`, which holds every `i32` exactly, so the parity, and sign are kept for odd `n`
+    /// beyond `2^24`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// let x = 2.0f32;
+    /// let abs_difference = (x.powi(2) - (x * x)).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// assert_eq!(Real::powi(-1.0_f32, 16_777_217), -1.0);
+    /// assert_eq!(Real::powi(-1.0_f32, i32::MAX), -1.0);
+    /// assert_eq!(Real::powi(-1.0_f64, i32::MAX), -1.0);
+    /// ```
+    #[must_use]
+    fn powi(self, n: i32) -> Self;
+
+    /// Takes the reciprocal (inverse) of a number, `1/x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = 2.0_f32;
+    /// let abs_difference = (x.recip() - (1.0 / x)).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn recip(self) -> Self;
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+    ///
+    /// In particular, the return value `r` satisfies `0.0 <= r < rhs.abs()` in
+    /// most cases. However, due to a floating point round-off error it can
+    /// result in `r == rhs.abs()`, violating the mathematical definition, if
+    /// `self` is much smaller than `rhs.abs()` in magnitude and `self < 0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let a: f32 = 7.0;
+    /// let b = 4.0;
+    ///
+    /// assert_eq!(a.rem_euclid(b), 3.0);
+    /// assert_eq!((-a).rem_euclid(b), 1.0);
+    /// assert_eq!(a.rem_euclid(-b), 3.0);
+    /// assert_eq!((-a).rem_euclid(-b), 1.0);
+    /// ```
+    #[must_use]
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Returns the nearest integer to a number. Round half-way cases away from
+    /// `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 3.3_f32;
+    /// let g = -3.3_f32;
+    /// let h = -3.7_f32;
+    ///
+    /// assert_eq!(f.round(), 3.0);
+    /// assert_eq!(g.round(), -3.0);
+    /// assert_eq!(h.round(), -4.0);
+    /// ```
+    #[must_use]
+    fn round(self) -> Self;
+
+    /// Returns the nearest integer to a number. Rounds half-way cases to the number
+    /// with an even least significant digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Real;
+    ///
+    /// let f = 3.3_f32;
+    /// let g = -3.3_f32;
+    /// let h = 3.5_f32;
+    /// let i = 4.5_f32;
+    ///
+    /// assert_eq!(Real::round_ties_even(f), 3.0);
+    /// assert_eq!(Real::round_ties_even(g), -3.0);
+    /// assert_eq!(Real::round_ties_even(h), 4.0);
+    /// assert_eq!(Real::round_ties_even(i), 4.0);
+    /// ```
+    #[must_use]
+    fn round_ties_even(self) -> Self;
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - NaN if the number is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 3.5_f32;
+    ///
+    /// assert_eq!(f.signum(), 1.0);
+    /// assert_eq!(f32::NEG_INFINITY.signum(), -1.0);
+    ///
+    /// assert!(f32::NAN.signum().is_nan());
+    /// ```
+    #[must_use]
+    fn signum(self) -> Self;
+
+    /// Computes the sine of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = std::f32::consts::FRAC_PI_2;
+    ///
+    /// let abs_difference = (x.sin() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn sin(self) -> Self;
+
+    /// Hyperbolic sine function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let e = std::f32::consts::E;
+    /// let x = 1.0f32;
+    ///
+    /// let f = x.sinh();
+    /// // Solving sinh() at 1 gives `(e^2-1)/(2e)`
+    /// let g = ((e * e) - 1.0) / (2.0 * e);
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn sinh(self) -> Self;
+
+    /// Simultaneously computes the sine and cosine of the number, `x`. Returns
+    /// `(sin(x), cos(x))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = std::f32::consts::FRAC_PI_4;
+    /// let f = x.sin_cos();
+    ///
+    /// let abs_difference_0 = (f.0 - x.sin()).abs();
+    /// let abs_difference_1 = (f.1 - x.cos()).abs();
+    ///
+    /// assert!(abs_difference_0 <= f32::EPSILON);
+    /// assert!(abs_difference_1 <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Returns the square root of a number, correctly rounded.
+    ///
+    /// Returns NaN if `self` is a negative number other than `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(const_trait_impl)]
+    ///
+    /// use meth::Real;
+    ///
+    /// const POSITIVE: f32 = Real::sqrt(4.0_f32);
+    ///
+    /// let negative = -4.0_f32;
+    /// let negative_zero = -0.0_f32;
+    ///
+    /// assert_eq!(POSITIVE, 2.0);
+    /// assert_eq!(Real::sqrt(4.0_f32), 2.0);
+    /// assert!(Real::sqrt(negative).is_nan());
+    /// assert!(Real::sqrt(negative_zero) == negative_zero);
+    /// ```
+    #[must_use]
+    fn sqrt(self) -> Self;
+
+    /// Computes the tangent of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = std::f32::consts::FRAC_PI_4;
+    /// let abs_difference = (x.tan() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn tan(self) -> Self;
+
+    /// Hyperbolic tangent function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let e = std::f32::consts::E;
+    /// let x = 1.0f32;
+    ///
+    /// let f = x.tanh();
+    /// // Solving tanh() at 1 gives `(1 - e^(-2))/(1 + e^(-2))`
+    /// let g = (1.0 - e.powi(-2)) / (1.0 + e.powi(-2));
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn tanh(self) -> Self;
+
+    /// Converts radians to degrees.
+    ///
+    /// ```
+    /// let angle = std::f32::consts::PI;
+    ///
+    /// let abs_difference = (angle.to_degrees() - 180.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn to_degrees(self) -> Self;
+
+    /// Converts degrees to radians.
+    ///
+    /// ```
+    /// let angle = 180.0f32;
+    ///
+    /// let abs_difference = (angle.to_radians() - std::f32::consts::PI).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[must_use]
+    fn to_radians(self) -> Self;
+
+    /// Returns the ordering between `self` and `other`.
+    ///
+    /// Unlike the standard partial comparison between floating point numbers,
+    /// this comparison always produces an ordering in accordance to
+    /// the `totalOrder` predicate as defined in the IEEE 754 (2008 revision)
+    /// floating point standard. The values are ordered in the following sequence:
+    ///
+    /// - negative quiet NaN
+    /// - negative signaling NaN
+    /// - negative infinity
+    /// - negative numbers
+    /// - negative subnormal numbers
+    /// - negative zero
+    /// - positive zero
+    /// - positive subnormal numbers
+    /// - positive numbers
+    /// - positive infinity
+    /// - positive signaling NaN
+    /// - positive quiet NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use meth::Real;
+    ///
+    /// assert_eq!(Real::total_cmp(&-0.0_f32, &0.0), Ordering::Less);
+    /// assert_eq!(Real::total_cmp(&f32::NAN, &f32::INFINITY), Ordering::Greater);
+    /// ```
+    #[must_use]
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns the integer part of a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = 3.7_f32;
+    /// let g = 3.0_f32;
+    /// let h = -3.7_f32;
+    ///
+    /// assert_eq!(f.trunc(), 3.0);
+    /// assert_eq!(g.trunc(), 3.0);
+    /// assert_eq!(h.trunc(), -3.0);
+    /// ```
+    #[must_use]
+    fn trunc(self) -> Self;
+}
+
+impl const Real for f32 {
+    const DIGITS: u32 = core::f32::DIGITS;
+    const EPSILON: f32 = core::f32::EPSILON;
+    const INFINITY: f32 = core::f32::INFINITY;
+    const MANTISSA_DIGITS: u32 = core::f32::MANTISSA_DIGITS;
+    const MAX: f32 = core::f32::MAX;
+    const MAX_10_EXP: i32 = core::f32::MAX_10_EXP;
+    const MAX_EXP: i32 = core::f32::MAX_EXP;
+    const MIN: f32 = core::f32::MIN;
+    const MIN_10_EXP: i32 = core::f32::MIN_10_EXP;
+    const MIN_EXP: i32 = core::f32::MIN_EXP;
+    const NAN: f32 = core::f32::NAN;
+    const NEG_INFINITY: f32 = core::f32::NEG_INFINITY;
+
+    const E: f32 = ::core::f32::consts::E;
+    const FRAC_1_PI: f32 = ::core::f32::consts::FRAC_1_PI;
+    const FRAC_1_SQRT_2: f32 = ::core::f32::consts::FRAC_1_SQRT_2;
+    const FRAC_2_PI: f32 = ::core::f32::consts::FRAC_2_PI;
+    const FRAC_2_SQRT_PI: f32 = ::core::f32::consts::FRAC_2_SQRT_PI;
+    const FRAC_PI_2: f32 = ::core::f32::consts::FRAC_PI_2;
+    const FRAC_PI_3: f32 = ::core::f32::consts::FRAC_PI_3;
+    const FRAC_PI_4: f32 = ::core::f32::consts::FRAC_PI_4;
+    const FRAC_PI_6: f32 = ::core::f32::consts::FRAC_PI_6;
+    const FRAC_PI_8: f32 = ::core::f32::consts::FRAC_PI_8;
+    const LN_2: f32 = ::core::f32::consts::LN_2;
+    const LN_10: f32 = ::core::f32::consts::LN_10;
+    const LOG2_10: f32 = ::core::f32::consts::LOG2_10;
+    const LOG2_E: f32 = ::core::f32::consts::LOG2_E;
+    const LOG10_2: f32 = ::core::f32::consts::LOG10_2;
+    const LOG10_E: f32 = ::core::f32::consts::LOG10_E;
+    const PI: f32 = <Self as Sealed>::_PI;
+    const SQRT_2: f32 = ::core::f32::consts::SQRT_2;
+    const TAU: f32 = ::core::f32::consts::TAU;
+
+    #[must_use]
+    #[inline]
+    fn abs(self) -> f32 {
+        libm::fabsf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn acos(self) -> f32 {
+        libm::acosf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn acosh(self) -> f32 {
+        libm::acoshf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn asin(self) -> f32 {
+        libm::asinf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn asinh(self) -> f32 {
+        libm::asinhf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn atan(self) -> f32 {
+        unsafe { const_eval_select((self,), soft::soft_f32::atan, libm::atanf) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn atan2(self, other: f32) -> f32 {
+        unsafe { const_eval_select((self, other), soft::soft_f32::atan2, libm::atan2f) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn atanh(self) -> f32 {
+        libm::atanhf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn cbrt(self) -> f32 {
+        libm::cbrtf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn ceil(self) -> f32 {
+        libm::ceilf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn clamp(self, min: f32, max: f32) -> f32 {
+        let mut x = self;
+
+        if x < min {
+            x = min;
+        }
+
+        if x > max {
+            x = max;
+        }
+
+        x
+    }
+
+    #[must_use]
+    #[inline]
+    fn classify(self) -> FpCategory {
+        let bits = self.to_bits();
+        let exponent = bits & 0x7f80_0000;
+        let mantissa = bits & 0x007f_ffff;
+
+        match (exponent, mantissa) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (0x7f80_0000, 0) => FpCategory::Infinite,
+            (0x7f80_0000, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn copysign(self, sign: f32) -> f32 {
+        libm::copysignf(self, sign)
+    }
+
+    #[must_use]
+    #[inline]
+    fn cos(self) -> f32 {
+        unsafe { const_eval_select((self,), soft::soft_f32::cos, libm::cosf) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn cosh(self) -> f32 {
+        libm::coshf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn div_euclid(self, rhs: f32) -> f32 {
+        let quotient = libm::truncf(self / rhs);
+
+        if self % rhs < 0.0 {
+            if rhs > 0.0 {
+                quotient - 1.0
+            } else {
+                quotient + 1.0
+            }
+        } else {
+            quotient
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn exp(self) -> f32 {
+        unsafe { const_eval_select((self,), soft::soft_f32::exp, libm::expf) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn exp2(self) -> f32 {
+        libm::exp2f(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn exp_m1(self) -> f32 {
+        libm::expm1f(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_atan2(self, other: f32) -> f32 {
+        fast::fast_f32::atan2(self, other)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_cos(self) -> f32 {
+        fast::fast_f32::sin_cos(self).1
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_exp(self) -> f32 {
+        fast::fast_f32::exp(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_recip(self) -> f32 {
+        fast::fast_f32::recip(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_rsqrt(self) -> f32 {
+        fast::fast_f32::rsqrt(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_sin(self) -> f32 {
+        fast::fast_f32::sin_cos(self).0
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_sin_cos(self) -> (f32, f32) {
+        fast::fast_f32::sin_cos(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn floor(self) -> f32 {
+        libm::floorf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fract(self) -> f32 {
+        self - libm::truncf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn hypot(self, other: f32) -> f32 {
+        libm::hypotf(self, other)
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.to_bits() & 0x7f80_0000 != 0x7f80_0000
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.to_bits() & !0x8000_0000 == 0x7f80_0000
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.to_bits() & !0x8000_0000 > 0x7f80_0000
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_normal(self) -> bool {
+        matches!(Real::classify(self), FpCategory::Normal)
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.to_bits() & 0x8000_0000 != 0
+    }
+
+    #[must_use]
+    #[inline]
+    fn ln(self) -> f32 {
+        unsafe { const_eval_select((self,), soft::soft_f32::ln, libm::logf) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn ln_1p(self) -> f32 {
+        libm::log1pf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn log(self, base: f32) -> f32 {
+        libm::logf(self) / libm::logf(base)
+    }
+
+    #[must_use]
+    #[inline]
+    fn log10(self) -> f32 {
+        libm::log10f(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn log2(self) -> f32 {
+        libm::log2f(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn max(self, other: f32) -> f32 {
         libm::fmaxf(self, other)
     }
 
@@ -381,22 +1536,99 @@ impl const Real for f32 {
         libm::fminf(self, other)
     }
 
+    #[must_use]
+    #[inline]
+    fn mul_add(self, a: f32, b: f32) -> f32 {
+        libm::fmaf(self, a, b)
+    }
+
+    #[must_use]
+    #[inline]
+    fn powf(self, n: f32) -> f32 {
+        libm::powf(self, n)
+    }
+
+    #[must_use]
+    #[inline]
+    fn powi(self, n: i32) -> f32 {
+        // `n as f32` rounds odd `n` beyond `2^24` to even
+        libm::pow(self as f64, n as f64) as f32
+    }
+
+    #[must_use]
+    #[inline]
+    fn recip(self) -> f32 {
+        1.0 / self
+    }
+
+    #[must_use]
+    #[inline]
+    fn rem_euclid(self, rhs: f32) -> f32 {
+        let remainder = self % rhs;
+
+        if remainder < 0.0 {
+            remainder + libm::fabsf(rhs)
+        } else {
+            remainder
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn round_ties_even(self) -> f32 {
+        libm::rintf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn signum(self) -> f32 {
+        if Real::is_nan(self) {
+            self
+        } else {
+            libm::copysignf(1.0, self)
+        }
+    }
+
     #[must_use]
     #[inline]
     fn sin(self) -> f32 {
-        libm::sinf(self)
+        unsafe { const_eval_select((self,), soft::soft_f32::sin, libm::sinf) }
     }
 
     #[must_use]
     #[inline]
     fn sin_cos(self) -> (f32, f32) {
-        libm::sincosf(self)
+        unsafe { const_eval_select((self,), soft::soft_f32::sin_cos, libm::sincosf) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn sinh(self) -> f32 {
+        libm::sinhf(self)
     }
 
     #[must_use]
     #[inline]
     fn sqrt(self) -> f32 {
-        libm::sqrtf(self)
+        unsafe { const_eval_select((self,), soft::soft_f32::sqrt, libm::sqrtf) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn tan(self) -> f32 {
+        libm::tanf(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn tanh(self) -> f32 {
+        libm::tanhf(self)
     }
 
     #[must_use]
@@ -410,6 +1642,31 @@ impl const Real for f32 {
     fn to_radians(self) -> f32 {
         self * <f32 as Sealed>::_PI_180
     }
+
+    #[must_use]
+    #[inline]
+    fn total_cmp(&self, other: &f32) -> Ordering {
+        let mut left = self.to_bits() as i32;
+        let mut right = other.to_bits() as i32;
+
+        // flip every bit but the sign of negative numbers, so they compare as two's complement
+        left ^= (((left >> 31) as u32) >> 1) as i32;
+        right ^= (((right >> 31) as u32) >> 1) as i32;
+
+        if left < right {
+            Ordering::Less
+        } else if left > right {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn trunc(self) -> f32 {
+        libm::truncf(self)
+    }
 }
 
 impl const Real for f64 {
@@ -452,16 +1709,58 @@ impl const Real for f64 {
         libm::fabs(self)
     }
 
+    #[must_use]
+    #[inline]
+    fn acos(self) -> f64 {
+        libm::acos(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn acosh(self) -> f64 {
+        libm::acosh(self)
+    }
+
     #[must_use]
     #[inline]
     fn asin(self) -> f64 {
         libm::asin(self)
     }
 
+    #[must_use]
+    #[inline]
+    fn asinh(self) -> f64 {
+        libm::asinh(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn atan(self) -> f64 {
+        unsafe { const_eval_select((self,), soft::soft_f64::atan, libm::atan) }
+    }
+
     #[must_use]
     #[inline]
     fn atan2(self, other: f64) -> f64 {
-        libm::atan2(self, other)
+        unsafe { const_eval_select((self, other), soft::soft_f64::atan2, libm::atan2) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn atanh(self) -> f64 {
+        libm::atanh(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
     }
 
     #[must_use]
@@ -480,6 +1779,22 @@ impl const Real for f64 {
         x
     }
 
+    #[must_use]
+    #[inline]
+    fn classify(self) -> FpCategory {
+        let bits = self.to_bits();
+        let exponent = bits & 0x7ff0_0000_0000_0000;
+        let mantissa = bits & 0x000f_ffff_ffff_ffff;
+
+        match (exponent, mantissa) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (0x7ff0_0000_0000_0000, 0) => FpCategory::Infinite,
+            (0x7ff0_0000_0000_0000, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        }
+    }
+
     #[must_use]
     #[inline]
     fn copysign(self, sign: f64) -> f64 {
@@ -489,7 +1804,167 @@ impl const Real for f64 {
     #[must_use]
     #[inline]
     fn cos(self) -> f64 {
-        libm::cos(self)
+        unsafe { const_eval_select((self,), soft::soft_f64::cos, libm::cos) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn cosh(self) -> f64 {
+        libm::cosh(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn div_euclid(self, rhs: f64) -> f64 {
+        let quotient = libm::trunc(self / rhs);
+
+        if self % rhs < 0.0 {
+            if rhs > 0.0 {
+                quotient - 1.0
+            } else {
+                quotient + 1.0
+            }
+        } else {
+            quotient
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn exp(self) -> f64 {
+        unsafe { const_eval_select((self,), soft::soft_f64::exp, libm::exp) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn exp2(self) -> f64 {
+        libm::exp2(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn exp_m1(self) -> f64 {
+        libm::expm1(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_atan2(self, other: f64) -> f64 {
+        fast::fast_f64::atan2(self, other)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_cos(self) -> f64 {
+        fast::fast_f64::sin_cos(self).1
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_exp(self) -> f64 {
+        fast::fast_f64::exp(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_recip(self) -> f64 {
+        fast::fast_f64::recip(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_rsqrt(self) -> f64 {
+        fast::fast_f64::rsqrt(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_sin(self) -> f64 {
+        fast::fast_f64::sin_cos(self).0
+    }
+
+    #[must_use]
+    #[inline]
+    fn fast_sin_cos(self) -> (f64, f64) {
+        fast::fast_f64::sin_cos(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn fract(self) -> f64 {
+        self - libm::trunc(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn hypot(self, other: f64) -> f64 {
+        libm::hypot(self, other)
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.to_bits() & 0x7ff0_0000_0000_0000 != 0x7ff0_0000_0000_0000
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.to_bits() & !0x8000_0000_0000_0000 == 0x7ff0_0000_0000_0000
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.to_bits() & !0x8000_0000_0000_0000 > 0x7ff0_0000_0000_0000
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_normal(self) -> bool {
+        matches!(Real::classify(self), FpCategory::Normal)
+    }
+
+    #[must_use]
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.to_bits() & 0x8000_0000_0000_0000 != 0
+    }
+
+    #[must_use]
+    #[inline]
+    fn ln(self) -> f64 {
+        unsafe { const_eval_select((self,), soft::soft_f64::ln, libm::log) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn ln_1p(self) -> f64 {
+        libm::log1p(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn log(self, base: f64) -> f64 {
+        libm::log(self) / libm::log(base)
+    }
+
+    #[must_use]
+    #[inline]
+    fn log10(self) -> f64 {
+        libm::log10(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn log2(self) -> f64 {
+        libm::log2(self)
     }
 
     #[must_use]
@@ -504,22 +1979,98 @@ impl const Real for f64 {
         libm::fmin(self, other)
     }
 
+    #[must_use]
+    #[inline]
+    fn mul_add(self, a: f64, b: f64) -> f64 {
+        libm::fma(self, a, b)
+    }
+
+    #[must_use]
+    #[inline]
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    #[must_use]
+    #[inline]
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    #[must_use]
+    #[inline]
+    fn recip(self) -> f64 {
+        1.0 / self
+    }
+
+    #[must_use]
+    #[inline]
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let remainder = self % rhs;
+
+        if remainder < 0.0 {
+            remainder + libm::fabs(rhs)
+        } else {
+            remainder
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn round_ties_even(self) -> f64 {
+        libm::rint(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn signum(self) -> f64 {
+        if Real::is_nan(self) {
+            self
+        } else {
+            libm::copysign(1.0, self)
+        }
+    }
+
     #[must_use]
     #[inline]
     fn sin(self) -> f64 {
-        libm::sin(self)
+        unsafe { const_eval_select((self,), soft::soft_f64::sin, libm::sin) }
     }
 
     #[must_use]
     #[inline]
     fn sin_cos(self) -> (f64, f64) {
-        libm::sincos(self)
+        unsafe { const_eval_select((self,), soft::soft_f64::sin_cos, libm::sincos) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn sinh(self) -> f64 {
+        libm::sinh(self)
     }
 
     #[must_use]
     #[inline]
     fn sqrt(self) -> f64 {
-        libm::sqrt(self)
+        unsafe { const_eval_select((self,), soft::soft_f64::sqrt, libm::sqrt) }
+    }
+
+    #[must_use]
+    #[inline]
+    fn tan(self) -> f64 {
+        libm::tan(self)
+    }
+
+    #[must_use]
+    #[inline]
+    fn tanh(self) -> f64 {
+        libm::tanh(self)
     }
 
     #[must_use]
@@ -533,4 +2084,29 @@ impl const Real for f64 {
     fn to_radians(self) -> f64 {
         self * <f64 as Sealed>::_PI_180
     }
+
+    #[must_use]
+    #[inline]
+    fn total_cmp(&self, other: &f64) -> Ordering {
+        let mut left = self.to_bits() as i64;
+        let mut right = other.to_bits() as i64;
+
+        // flip every bit but the sign of negative numbers, so they compare as two's complement
+        left ^= (((left >> 63) as u64) >> 1) as i64;
+        right ^= (((right >> 63) as u64) >> 1) as i64;
+
+        if left < right {
+            Ordering::Less
+        } else if left > right {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn trunc(self) -> f64 {
+        libm::trunc(self)
+    }
 }
@@ -0,0 +1,181 @@
+//! Fast approximations, trading accuracy for speed.
+//!
+//! Every function is branch-free arithmetic, or a few comparisons, so loops over them vectorize.
+//! Coefficients are near-minimax polynomials (Chebyshev fits), and are shared by `f32` and `f64`,
+//! so `f64` is no more accurate than `f32`.
+
+macro_rules! impl_fast {
+    { $module:ident: $ty:ident, $bits:ty, $int:ty, $mantissa:literal, $bias:literal, $rsqrt:literal, $recip:literal } => {
+        pub(super) mod $module {
+            const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+
+            /// `2^i`, `i` must be within the normal exponent range.
+            #[inline]
+            const fn exp2i(i: $int) -> $ty {
+                <$ty>::from_bits(((i + $bias) as $bits) << $mantissa)
+            }
+
+            /// Round half away from zero, saturating.
+            #[inline]
+            const fn round(x: $ty) -> $int {
+                (if x < 0.0 { x - 0.5 } else { x + 0.5 }) as $int
+            }
+
+            /// Round towards negative infinity, saturating.
+            #[inline]
+            const fn floor(x: $ty) -> $int {
+                let i = x as $int;
+
+                if (i as $ty) > x {
+                    i - 1
+                } else {
+                    i
+                }
+            }
+
+            /// Initial estimate from the bit pattern, refined with two Newton-Raphson steps.
+            ///
+            /// Negative numbers are NaN, zero and subnormal numbers are infinity with the sign of
+            /// `x`, and infinity is zero.
+            #[inline]
+            pub const fn rsqrt(x: $ty) -> $ty {
+                // wraps for negative numbers, which are replaced below
+                let y = <$ty>::from_bits(<$bits>::wrapping_sub($rsqrt, x.to_bits() >> 1));
+                let y = y * (1.5 - 0.5 * x * y * y);
+                let y = y * (1.5 - 0.5 * x * y * y);
+
+                if x < 0.0 {
+                    <$ty>::NAN
+                } else if x < <$ty>::MIN_POSITIVE {
+                    <$ty>::from_bits(<$ty>::INFINITY.to_bits() | (x.to_bits() & SIGN))
+                } else if x == <$ty>::INFINITY {
+                    0.0
+                } else {
+                    y
+                }
+            }
+
+            /// Initial estimate from the bit pattern of `|x|`, refined with two Newton-Raphson
+            /// steps, then given the sign of `x`.
+            ///
+            /// Zero and subnormal numbers are infinity, numbers with a subnormal reciprocal, and
+            /// infinity are zero, both with the sign of `x`.
+            #[inline]
+            pub const fn recip(x: $ty) -> $ty {
+                let abs = <$ty>::from_bits(x.to_bits() & !SIGN);
+
+                // the estimate is subnormal, and inaccurate, above `2^(MAX_EXP - 3)`, so halve
+                // the largest numbers, and the result
+                let scale = if abs > 0.5 / <$ty>::MIN_POSITIVE { 0.5 } else { 1.0 };
+                let a = abs * scale;
+
+                // wraps for infinity, and NaN, which are replaced below, or NaN after the first
+                // step
+                let y = <$ty>::from_bits(<$bits>::wrapping_sub($recip, a.to_bits()));
+                let y = y * (2.0 - a * y);
+                let y = y * (2.0 - a * y) * scale;
+
+                let y = if abs < <$ty>::MIN_POSITIVE {
+                    <$ty>::INFINITY
+                } else if abs > 1.0 / <$ty>::MIN_POSITIVE {
+                    0.0
+                } else {
+                    y
+                };
+
+                <$ty>::from_bits(y.to_bits() | (x.to_bits() & SIGN))
+            }
+
+            /// Reduces `x` to `[-π/4, π/4]`, then swaps, and negates according to the quadrant.
+            #[inline]
+            #[allow(clippy::excessive_precision)]
+            pub const fn sin_cos(x: $ty) -> ($ty, $ty) {
+                let quadrant = round(x * ::core::$ty::consts::FRAC_2_PI);
+                let k = quadrant as $ty;
+
+                // π/2 in two parts, the first part is exact when multiplied by `k`
+                let r = x - k * 1.5703125 - k * 4.838267948966e-4;
+                let r2 = r * r;
+
+                let sin = r
+                    * (0.9999985632639604
+                        + r2 * (-0.16662472194586495 + r2 * 0.008151506332465959));
+
+                let cos = 0.9999999723284944
+                    + r2 * (-0.4999985641918218
+                        + r2 * (0.04165501492488376 + r2 * -0.0013585779264842408));
+
+                let (sin, cos) = if quadrant & 1 != 0 { (cos, sin) } else { (sin, cos) };
+                let sin = if quadrant & 2 != 0 { -sin } else { sin };
+                let cos = if quadrant.wrapping_add(1) & 2 != 0 { -cos } else { cos };
+
+                (sin, cos)
+            }
+
+            /// Evaluates `atan` of the smaller over the larger magnitude, within `[0, 1]`, then
+            /// reflects into the correct octant.
+            #[inline]
+            #[allow(clippy::excessive_precision)]
+            pub const fn atan2(y: $ty, x: $ty) -> $ty {
+                let abs_x = <$ty>::from_bits(x.to_bits() & !SIGN);
+                let abs_y = <$ty>::from_bits(y.to_bits() & !SIGN);
+                let (min, max) = if abs_x < abs_y { (abs_x, abs_y) } else { (abs_y, abs_x) };
+
+                // `0 / 0` is NaN
+                let a = if max == 0.0 { 0.0 } else { min / max };
+                let a2 = a * a;
+                let output = a
+                    * (0.9999647984014695
+                        + a2 * (-0.3315446193087245
+                            + a2 * (0.18446355750908083
+                                + a2 * (-0.09075201792469148 + a2 * 0.02328600773299502))));
+
+                let output = if abs_y > abs_x {
+                    ::core::$ty::consts::FRAC_PI_2 - output
+                } else {
+                    output
+                };
+
+                let output = if x.to_bits() & SIGN != 0 {
+                    ::core::$ty::consts::PI - output
+                } else {
+                    output
+                };
+
+                // `output` is positive, take the sign of `y`
+                <$ty>::from_bits(output.to_bits() | (y.to_bits() & SIGN))
+            }
+
+            /// `e^x = 2^i · 2^f`, where `i` is an integer, and `f` is within `[0, 1)`.
+            #[inline]
+            #[allow(clippy::excessive_precision)]
+            pub const fn exp(x: $ty) -> $ty {
+                let t = x * ::core::$ty::consts::LOG2_E;
+
+                if t < (1 - $bias) as $ty {
+                    return 0.0;
+                }
+
+                if t >= ($bias + 1) as $ty {
+                    return <$ty>::INFINITY;
+                }
+
+                let i = floor(t);
+                let f = t - i as $ty;
+                let p = 1.0000034929076982
+                    + f * (0.692972922173052
+                        + f * (0.24160435727009005
+                            + f * (0.05174499776411005 + f * 0.01367030945335438)));
+
+                // `2^i` in two halves, as `i` may exceed the exponent range by one
+                let i1 = i >> 1;
+                let i2 = i - i1;
+
+                p * exp2i(i1) * exp2i(i2)
+            }
+        }
+    };
+}
+
+impl_fast! { fast_f32: f32, u32, i32, 23, 127, 0x5f37_5a86, 0x7ef3_11c7 }
+impl_fast! { fast_f64: f64, u64, i64, 52, 1023, 0x5fe6_eb50_c7b5_37a9, 0x7fde_6238_2283_5eea }
@@ -0,0 +1,611 @@
+//! Implementations independent of `libm`, used when evaluating in const contexts.
+//!
+//! Ported from fdlibm, the same source as `libm`, with exact Payne-Hanek range reduction for
+//! `sin`, and `cos`. `sqrt` is correctly rounded, everything else is within one ulp. `f32` is
+//! evaluated in `f64`, and rounded once, which keeps `sqrt` correctly rounded, as `f64` has more
+//! than twice the precision.
+
+pub(super) mod soft_f64 {
+    const SIGN: u64 = 1 << 63;
+
+    /// Bits of `2/π`, most significant first.
+    const FRAC_2_PI: [u64; 20] = [
+        0xa2f9_836e_4e44_1529,
+        0xfc27_57d1_f534_ddc0,
+        0xdb62_9599_3c43_9041,
+        0xfe51_63ab_debb_c561,
+        0xb724_6e3a_424d_d2e0,
+        0x0649_2eea_09d1_921c,
+        0xfe1d_eb1c_b129_a73e,
+        0xe882_35f5_2ebb_4484,
+        0xe99c_7026_b45f_7e41,
+        0x3991_d639_8353_39f4,
+        0x9c84_5f8b_bdf9_283b,
+        0x1ff8_97ff_de05_980f,
+        0xef2f_118b_5a0a_6d1f,
+        0x6d36_7ecf_27cb_09b7,
+        0x4f46_3f66_9e5f_ea2d,
+        0x7527_bac7_ebe5_f17b,
+        0x3d07_39f7_8a52_92ea,
+        0x6bfb_5fb1_1f8d_5d08,
+        0x5603_3046_fc7b_6bab,
+        0xf0cf_bc20_9af4_361d,
+    ];
+
+    const FRAC_PI_2_HI: f64 = ::core::f64::consts::FRAC_PI_2;
+    const FRAC_PI_2_LO: f64 = 6.123_233_995_736_766e-17;
+
+    /// `x · 2^n`, `n` must be within `[-1991, 2046]`.
+    const fn scalbn(mut x: f64, mut n: i32) -> f64 {
+        if n > 1023 {
+            x *= f64::from_bits(0x7fe0_0000_0000_0000);
+            n -= 1023;
+        } else if n < -1022 {
+            // `2^-1022 · 2^53`, so a subnormal result is rounded once
+            x *= f64::from_bits(0x0360_0000_0000_0000);
+            n += 1022 - 53;
+        }
+
+        x * f64::from_bits(((0x3ff + n) as u64) << 52)
+    }
+
+    /// Integer square root, and remainder, digit by digit.
+    const fn isqrt(n: u128) -> (u128, u128) {
+        let mut root = 0;
+        let mut remainder = n;
+        let mut bit = 1 << 126;
+
+        while bit > n {
+            bit >>= 2;
+        }
+
+        while bit != 0 {
+            if remainder >= root + bit {
+                remainder -= root + bit;
+                root = (root >> 1) + bit;
+            } else {
+                root >>= 1;
+            }
+
+            bit >>= 2;
+        }
+
+        (root, remainder)
+    }
+
+    /// Correctly rounded square root.
+    pub const fn sqrt(x: f64) -> f64 {
+        let bits = x.to_bits();
+
+        // NaN, infinity, and zeros of either sign
+        #[allow(clippy::eq_op)]
+        if x != x || x == 0.0 || x == f64::INFINITY {
+            return x;
+        }
+
+        if bits & SIGN != 0 {
+            return f64::NAN;
+        }
+
+        let mut exponent = (bits >> 52) as i32;
+        let mut mantissa = bits & ((1 << 52) - 1);
+
+        if exponent == 0 {
+            let shift = mantissa.leading_zeros() - 11;
+
+            mantissa <<= shift;
+            exponent = 1 - shift as i32;
+        } else {
+            mantissa |= 1 << 52;
+        }
+
+        // `x = mantissa · 2^exponent`, with an even exponent
+        let mut exponent = exponent - 1075;
+
+        if exponent & 1 != 0 {
+            mantissa <<= 1;
+            exponent -= 1;
+        }
+
+        // `root` has 53 bits, round to nearest, ties are impossible
+        let (root, remainder) = isqrt((mantissa as u128) << 52);
+        let root = (if remainder > root { root + 1 } else { root }) as u64;
+        let exponent = exponent / 2 - 26;
+
+        let (root, exponent) = if root >> 53 != 0 {
+            (root >> 1, exponent + 1)
+        } else {
+            (root, exponent)
+        };
+
+        f64::from_bits((((exponent + 52 + 1023) as u64) << 52) + (root - (1 << 52)))
+    }
+
+    /// `x - n·π/2` as `hi + lo`, within `[-π/4, π/4]`, and `n` modulo 4.
+    ///
+    /// `|x|` must be finite.
+    const fn rem_pio2(x: f64) -> (u32, f64, f64) {
+        let bits = x.to_bits();
+        let mantissa = (bits & ((1 << 52) - 1)) | 1 << 52;
+        let exponent = ((bits >> 52) & 0x7ff) as i32 - 1075;
+
+        // bits of `2/π` before `start` contribute multiples of 4 to `x · 2/π`
+        let start = if exponent > 2 {
+            exponent as usize - 2
+        } else {
+            0
+        };
+        let index = start / 64;
+        let shift = start % 64;
+        let mut window = [0; 3];
+        let mut i = 0;
+
+        while i < 3 {
+            let pair = (FRAC_2_PI[index + i] as u128) << 64 | FRAC_2_PI[index + i + 1] as u128;
+
+            window[i] = (pair >> (64 - shift)) as u64;
+            i += 1;
+        }
+
+        // `mantissa · window`, the product has 190 fractional bits
+        let mut product = [0; 4];
+        let mut carry = 0;
+        let mut i = 3;
+
+        while i > 0 {
+            let partial = mantissa as u128 * window[i - 1] as u128 + carry;
+
+            product[i] = partial as u64;
+            carry = partial >> 64;
+            i -= 1;
+        }
+
+        product[0] = carry as u64;
+
+        if exponent < 2 {
+            let shift = (2 - exponent) as u32;
+            let mut i = 3;
+
+            while i > 0 {
+                product[i] = product[i] >> shift | product[i - 1] << (64 - shift);
+                i -= 1;
+            }
+        }
+
+        // round to the nearest quadrant, the remaining fraction is within `[-0.5, 0.5)`
+        let top = product[1].wrapping_add(1 << 61);
+        let quadrant = (top >> 62) as u32;
+        let top = top & ((1 << 62) - 1);
+        let low = (product[2] as u128) << 64 | product[3] as u128;
+
+        let (negative, top, low) = if top < 1 << 61 {
+            let borrow = (low != 0) as u64;
+
+            (true, (1 << 61) - top - borrow, low.wrapping_neg())
+        } else {
+            (false, top - (1 << 61), low)
+        };
+
+        // the fraction as 128 bits, scaled by `2^scale`
+        let (fraction, scale) = if top != 0 {
+            let zeros = top.leading_zeros();
+
+            (
+                (top as u128) << (64 + zeros) | low >> (64 - zeros),
+                -126 - zeros as i32,
+            )
+        } else if low != 0 {
+            let zeros = low.leading_zeros();
+
+            (low << zeros, -190 - zeros as i32)
+        } else {
+            (0, 0)
+        };
+
+        let hi = scalbn((fraction >> 75) as f64, scale + 75);
+        let lo = scalbn((fraction & ((1 << 75) - 1)) as f64, scale);
+        let (hi, lo) = mul(hi, lo, FRAC_PI_2_HI, FRAC_PI_2_LO);
+        let (hi, lo) = if negative { (-hi, -lo) } else { (hi, lo) };
+
+        if bits & SIGN != 0 {
+            (quadrant.wrapping_neg() & 3, -hi, -lo)
+        } else {
+            (quadrant, hi, lo)
+        }
+    }
+
+    /// Splits `x` into two halves, whose product with another half is exact.
+    const fn split(x: f64) -> (f64, f64) {
+        let c = 134_217_729.0 * x;
+        let hi = c - (c - x);
+
+        (hi, x - hi)
+    }
+
+    /// `(a_hi + a_lo) · (b_hi + b_lo)` as `hi + lo`.
+    const fn mul(a_hi: f64, a_lo: f64, b_hi: f64, b_lo: f64) -> (f64, f64) {
+        let product = a_hi * b_hi;
+        let (a_hi_hi, a_hi_lo) = split(a_hi);
+        let (b_hi_hi, b_hi_lo) = split(b_hi);
+        let error = ((a_hi_hi * b_hi_hi - product) + a_hi_hi * b_hi_lo + a_hi_lo * b_hi_hi)
+            + a_hi_lo * b_hi_lo;
+        let error = error + (a_hi * b_lo + a_lo * b_hi);
+        let hi = product + error;
+
+        (hi, error - (hi - product))
+    }
+
+    /// Sine of `x + y` within `[-π/4, π/4]`.
+    #[allow(clippy::excessive_precision)]
+    const fn kernel_sin(x: f64, y: f64) -> f64 {
+        const S1: f64 = -1.66666666666666324348e-01;
+        const S2: f64 = 8.33333333332248946124e-03;
+        const S3: f64 = -1.98412698298579493134e-04;
+        const S4: f64 = 2.75573137070700676789e-06;
+        const S5: f64 = -2.50507602534068634195e-08;
+        const S6: f64 = 1.58969099521155010221e-10;
+
+        let z = x * x;
+        let w = z * z;
+        let r = S2 + z * (S3 + z * S4) + z * w * (S5 + z * S6);
+        let v = z * x;
+
+        x - ((z * (0.5 * y - v * r) - y) - v * S1)
+    }
+
+    /// Cosine of `x + y` within `[-π/4, π/4]`.
+    #[allow(clippy::excessive_precision)]
+    const fn kernel_cos(x: f64, y: f64) -> f64 {
+        const C1: f64 = 4.16666666666666019037e-02;
+        const C2: f64 = -1.38888888888741095749e-03;
+        const C3: f64 = 2.48015872894767294178e-05;
+        const C4: f64 = -2.75573143513906633035e-07;
+        const C5: f64 = 2.08757232129817482790e-09;
+        const C6: f64 = -1.13596475577881948265e-11;
+
+        let z = x * x;
+        let w = z * z;
+        let r = z * (C1 + z * (C2 + z * C3)) + w * w * (C4 + z * (C5 + z * C6));
+        let hz = 0.5 * z;
+        let w = 1.0 - hz;
+
+        w + (((1.0 - w) - hz) + (z * r - x * y))
+    }
+
+    pub const fn sin_cos(x: f64) -> (f64, f64) {
+        let abs = x.to_bits() & !SIGN;
+
+        // NaN, and infinity
+        if abs >= 0x7ff0_0000_0000_0000 {
+            return (f64::NAN, f64::NAN);
+        }
+
+        // |x| < 2^-26
+        if abs < 0x3e50_0000_0000_0000 {
+            return (x, 1.0);
+        }
+
+        // |x| <= π/4
+        if abs <= 0x3fe9_21fb_5444_2d18 {
+            return (kernel_sin(x, 0.0), kernel_cos(x, 0.0));
+        }
+
+        let (quadrant, hi, lo) = rem_pio2(x);
+        let sin = kernel_sin(hi, lo);
+        let cos = kernel_cos(hi, lo);
+
+        match quadrant {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        }
+    }
+
+    pub const fn sin(x: f64) -> f64 {
+        sin_cos(x).0
+    }
+
+    pub const fn cos(x: f64) -> f64 {
+        sin_cos(x).1
+    }
+
+    #[allow(clippy::excessive_precision)]
+    pub const fn atan(x: f64) -> f64 {
+        const ATAN_HI: [f64; 4] = [
+            4.63647609000806093515e-01,
+            ::core::f64::consts::FRAC_PI_4,
+            9.82793723247329054082e-01,
+            ::core::f64::consts::FRAC_PI_2,
+        ];
+
+        const ATAN_LO: [f64; 4] = [
+            2.26987774529616870924e-17,
+            3.06161699786838301793e-17,
+            1.39033110312309984516e-17,
+            6.12323399573676603587e-17,
+        ];
+
+        const AT: [f64; 11] = [
+            3.33333333333329318027e-01,
+            -1.99999999998764832476e-01,
+            1.42857142725034663711e-01,
+            -1.11111104054623557880e-01,
+            9.09088713343650656196e-02,
+            -7.69187620504482999495e-02,
+            6.66107313738753120669e-02,
+            -5.83357013379057348645e-02,
+            4.97687799461593236017e-02,
+            -3.65315727442169155270e-02,
+            1.62858201153657823623e-02,
+        ];
+
+        let negative = x.to_bits() & SIGN != 0;
+        let high = (x.to_bits() >> 32) as u32 & 0x7fff_ffff;
+
+        // |x| >= 2^66
+        if high >= 0x4410_0000 {
+            #[allow(clippy::eq_op)]
+            if x != x {
+                return x;
+            }
+
+            return if negative { -ATAN_HI[3] } else { ATAN_HI[3] };
+        }
+
+        let (id, x) = if high < 0x3fdc_0000 {
+            // |x| < 2^-27
+            if high < 0x3e40_0000 {
+                return x;
+            }
+
+            (None, x)
+        } else {
+            let x = if negative { -x } else { x };
+
+            if high < 0x3fe6_0000 {
+                (Some(0), (2.0 * x - 1.0) / (2.0 + x))
+            } else if high < 0x3ff3_0000 {
+                (Some(1), (x - 1.0) / (x + 1.0))
+            } else if high < 0x4003_8000 {
+                (Some(2), (x - 1.5) / (1.0 + 1.5 * x))
+            } else {
+                (Some(3), -1.0 / x)
+            }
+        };
+
+        let z = x * x;
+        let w = z * z;
+        let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+        let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+
+        match id {
+            None => x - x * (s1 + s2),
+            Some(id) => {
+                let z = ATAN_HI[id] - ((x * (s1 + s2) - ATAN_LO[id]) - x);
+
+                if negative {
+                    -z
+                } else {
+                    z
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::excessive_precision)]
+    pub const fn atan2(y: f64, x: f64) -> f64 {
+        const PI: f64 = ::core::f64::consts::PI;
+        const PI_LO: f64 = 1.2246467991473531772e-16;
+
+        #[allow(clippy::eq_op)]
+        if x != x || y != y {
+            return x + y;
+        }
+
+        if x == 1.0 {
+            return atan(y);
+        }
+
+        // `2 · sign(x) + sign(y)`
+        let quadrant = (y.to_bits() >> 63) | (x.to_bits() >> 62) & 2;
+        let abs_x = x.to_bits() & !SIGN;
+        let abs_y = y.to_bits() & !SIGN;
+        let high_x = (abs_x >> 32) as u32;
+        let high_y = (abs_y >> 32) as u32;
+
+        if abs_y == 0 {
+            return match quadrant {
+                0 | 1 => y,
+                2 => PI,
+                _ => -PI,
+            };
+        }
+
+        if abs_x == 0 {
+            return if quadrant & 1 != 0 {
+                -PI / 2.0
+            } else {
+                PI / 2.0
+            };
+        }
+
+        if high_x == 0x7ff0_0000 {
+            return match (high_y == 0x7ff0_0000, quadrant) {
+                (true, 0) => PI / 4.0,
+                (true, 1) => -PI / 4.0,
+                (true, 2) => 3.0 * PI / 4.0,
+                (true, _) => -3.0 * PI / 4.0,
+                (false, 0) => 0.0,
+                (false, 1) => -0.0,
+                (false, 2) => PI,
+                (false, _) => -PI,
+            };
+        }
+
+        // |y/x| > 2^64
+        if high_x + (64 << 20) < high_y || high_y == 0x7ff0_0000 {
+            return if quadrant & 1 != 0 {
+                -PI / 2.0
+            } else {
+                PI / 2.0
+            };
+        }
+
+        // |y/x| < 2^-64, and x < 0
+        let z = if quadrant & 2 != 0 && high_y + (64 << 20) < high_x {
+            0.0
+        } else {
+            atan(f64::from_bits((y / x).to_bits() & !SIGN))
+        };
+
+        match quadrant {
+            0 => z,
+            1 => -z,
+            2 => PI - (z - PI_LO),
+            _ => (z - PI_LO) - PI,
+        }
+    }
+
+    #[allow(clippy::excessive_precision)]
+    pub const fn exp(x: f64) -> f64 {
+        const LN_2_HI: f64 = 6.93147180369123816490e-01;
+        const LN_2_LO: f64 = 1.90821492927058770002e-10;
+        const P1: f64 = 1.66666666666666019037e-01;
+        const P2: f64 = -2.77777777770155933842e-03;
+        const P3: f64 = 6.61375632143793436117e-05;
+        const P4: f64 = -1.65339022054652515390e-06;
+        const P5: f64 = 4.13813679705723846039e-08;
+
+        #[allow(clippy::eq_op)]
+        if x != x {
+            return x;
+        }
+
+        if x > 7.09782712893383973096e+02 {
+            return f64::INFINITY;
+        }
+
+        if x < -7.45133219101941108420e+02 {
+            return 0.0;
+        }
+
+        let abs = x.to_bits() & !SIGN;
+
+        // |x| > ln(2) / 2
+        let (k, hi, lo) = if abs > 0x3fd6_2e42_fefa_39ef {
+            let half = if x < 0.0 { -0.5 } else { 0.5 };
+            let k = (::core::f64::consts::LOG2_E * x + half) as i32;
+
+            (k, x - k as f64 * LN_2_HI, k as f64 * LN_2_LO)
+        } else if abs > 0x3e30_0000_0000_0000 {
+            (0, x, 0.0)
+        } else {
+            // |x| <= 2^-28
+            return 1.0 + x;
+        };
+
+        let x = hi - lo;
+        let xx = x * x;
+        let c = x - xx * (P1 + xx * (P2 + xx * (P3 + xx * (P4 + xx * P5))));
+        let y = 1.0 + (x * c / (2.0 - c) - lo + hi);
+
+        if k == 0 {
+            y
+        } else {
+            scalbn(y, k)
+        }
+    }
+
+    #[allow(clippy::excessive_precision)]
+    pub const fn ln(x: f64) -> f64 {
+        const LN_2_HI: f64 = 6.93147180369123816490e-01;
+        const LN_2_LO: f64 = 1.90821492927058770002e-10;
+        const LG1: f64 = 6.666666666666735130e-01;
+        const LG2: f64 = 3.999999999940941908e-01;
+        const LG3: f64 = 2.857142874366239149e-01;
+        const LG4: f64 = 2.222219843214978396e-01;
+        const LG5: f64 = 1.818357216161805012e-01;
+        const LG6: f64 = 1.531383769920937332e-01;
+        const LG7: f64 = 1.479819860511658591e-01;
+
+        let mut bits = x.to_bits();
+        let mut high = (bits >> 32) as u32;
+        let mut k = 0;
+
+        if high < 0x0010_0000 || high >> 31 != 0 {
+            if bits << 1 == 0 {
+                return f64::NEG_INFINITY;
+            }
+
+            if high >> 31 != 0 {
+                return f64::NAN;
+            }
+
+            // subnormal, scale up
+            k -= 54;
+            bits = (x * f64::from_bits(0x4350_0000_0000_0000)).to_bits();
+            high = (bits >> 32) as u32;
+        } else if high >= 0x7ff0_0000 {
+            return x;
+        } else if bits == 0x3ff0_0000_0000_0000 {
+            return 0.0;
+        }
+
+        // reduce `x` into `[sqrt(2) / 2, sqrt(2)]`
+        high += 0x3ff0_0000 - 0x3fe6_a09e;
+        k += (high >> 20) as i32 - 0x3ff;
+        high = (high & 0x000f_ffff) + 0x3fe6_a09e;
+
+        let x = f64::from_bits((high as u64) << 32 | bits & 0xffff_ffff);
+        let f = x - 1.0;
+        let hfsq = 0.5 * f * f;
+        let s = f / (2.0 + f);
+        let z = s * s;
+        let w = z * z;
+        let t1 = w * (LG2 + w * (LG4 + w * LG6));
+        let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+        let r = t2 + t1;
+        let k = k as f64;
+
+        s * (hfsq + r) + k * LN_2_LO - hfsq + f + k * LN_2_HI
+    }
+}
+
+pub(super) mod soft_f32 {
+    use super::soft_f64;
+
+    pub const fn sqrt(x: f32) -> f32 {
+        soft_f64::sqrt(x as f64) as f32
+    }
+
+    pub const fn sin(x: f32) -> f32 {
+        soft_f64::sin(x as f64) as f32
+    }
+
+    pub const fn cos(x: f32) -> f32 {
+        soft_f64::cos(x as f64) as f32
+    }
+
+    pub const fn sin_cos(x: f32) -> (f32, f32) {
+        let (sin, cos) = soft_f64::sin_cos(x as f64);
+
+        (sin as f32, cos as f32)
+    }
+
+    pub const fn atan(x: f32) -> f32 {
+        soft_f64::atan(x as f64) as f32
+    }
+
+    pub const fn atan2(y: f32, x: f32) -> f32 {
+        soft_f64::atan2(y as f64, x as f64) as f32
+    }
+
+    pub const fn exp(x: f32) -> f32 {
+        soft_f64::exp(x as f64) as f32
+    }
+
+    pub const fn ln(x: f32) -> f32 {
+        soft_f64::ln(x as f64) as f32
+    }
+}
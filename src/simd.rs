@@ -0,0 +1,412 @@
+use crate::identity::{One, Zero};
+use crate::intrinsics;
+use crate::intrinsics::const_eval_select;
+use crate::vec::{Element, Kernel, Mask};
+use cake::mem;
+use core::cmp::{PartialEq, PartialOrd};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use core::ops::{BitAnd, BitOr, BitXor};
+use core::ops::{Deref, DerefMut};
+
+mod cmp;
+mod fma;
+mod minmax;
+mod product;
+mod reduce;
+mod sum;
+
+pub(crate) mod shuffle;
+
+/// Largest number of lanes of a [`Simd`], the limit of `repr(simd)`.
+pub(crate) const MAX_LANES: usize = 1 << 15;
+
+/// Fixed width SIMD vector of `LANES` lanes.
+///
+/// Every operation is `const`, lanes are computed one at a time in const contexts, and with
+/// platform intrinsics otherwise. Arithmetic, comparisons, and reductions of [`Vec`](crate::Vec)
+/// are built on top of it, processing [`Vec::LANES`](crate::Vec::LANES) lanes at a time.
+///
+/// Integer division, and remainder, panic on a zero divisor, or on overflow, same as `/` and
+/// `%`.
+///
+/// `LANES` must be within `1..=32768`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(const_trait_impl)]
+///
+/// use meth::Simd;
+///
+/// const A: Simd<f32, 4> = Simd::from_array([1.0, 2.0, 3.0, 4.0]);
+/// const B: Simd<f32, 4> = Simd::splat(2.0);
+/// const SUM: f32 = (A * B + B).sum();
+///
+/// assert_eq!(SUM, 28.0);
+/// assert_eq!((A * B + B).sum(), SUM);
+/// assert_eq!(*A.reverse(), [4.0, 3.0, 2.0, 1.0]);
+/// assert!(A.lanes_lt(B).any());
+/// ```
+///
+/// ```should_panic
+/// use meth::Simd;
+///
+/// let a = Simd::from_array([i32::MIN, 4, 6, 8]);
+/// let b = Simd::from_array([-1, 2, 3, 4]);
+///
+/// // attempt to divide with overflow
+/// let _ = a / b;
+/// ```
+#[derive(Clone, Copy)]
+#[repr(simd)]
+pub struct Simd<T, const LANES: usize>([T; LANES]);
+
+impl<T, const LANES: usize> Simd<T, LANES> {
+    /// Converts an array to a SIMD vector.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(array: [T; LANES]) -> Simd<T, LANES> {
+        Self(array)
+    }
+
+    /// Converts a SIMD vector to an array.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [T; LANES]
+    where
+        T: Copy,
+    {
+        // SAFETY: it's either this or llvm crashing
+        unsafe { mem::transmute(self) }
+    }
+
+    /// Creates a new SIMD vector with all lanes set to the given value.
+    #[inline]
+    #[must_use]
+    pub const fn splat(value: T) -> Simd<T, LANES>
+    where
+        T: Copy,
+    {
+        Self::from_array([value; LANES])
+    }
+
+    #[inline]
+    pub const fn product(self) -> T
+    where
+        T: ~const Element,
+        T: ~const One,
+        T: ~const Mul<Output = T>,
+    {
+        product::product(self)
+    }
+
+    /// Same as [`product`](Self::product), but multiplies lanes in an unspecified order.
+    #[inline]
+    pub const fn product_unordered(self) -> T
+    where
+        T: ~const Element,
+        T: ~const One,
+        T: ~const Mul<Output = T>,
+    {
+        product::product_unordered(self)
+    }
+
+    #[inline]
+    pub const fn sum(self) -> T
+    where
+        T: ~const Element,
+        T: ~const Zero,
+        T: ~const Add<Output = T>,
+    {
+        sum::sum(self)
+    }
+
+    /// Same as [`sum`](Self::sum), but adds lanes in an unspecified order.
+    #[inline]
+    pub const fn sum_unordered(self) -> T
+    where
+        T: ~const Element,
+        T: ~const Zero,
+        T: ~const Add<Output = T>,
+    {
+        sum::sum_unordered(self)
+    }
+
+    /// Returns the minimum lane.
+    ///
+    /// For floats, NaN lanes are ignored, unless all lanes are NaN.
+    #[inline]
+    #[must_use]
+    pub const fn min_element(self) -> T
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+    {
+        reduce::min_element(self)
+    }
+
+    /// Returns the maximum lane.
+    ///
+    /// For floats, NaN lanes are ignored, unless all lanes are NaN.
+    #[inline]
+    #[must_use]
+    pub const fn max_element(self) -> T
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+    {
+        reduce::max_element(self)
+    }
+
+    /// Returns the bitwise AND of all lanes.
+    #[inline]
+    #[must_use]
+    pub const fn reduce_and(self) -> T
+    where
+        T: ~const Element,
+        T: ~const BitAnd<Output = T>,
+    {
+        reduce::reduce_and(self)
+    }
+
+    /// Returns the bitwise OR of all lanes.
+    #[inline]
+    #[must_use]
+    pub const fn reduce_or(self) -> T
+    where
+        T: ~const Element,
+        T: ~const BitOr<Output = T>,
+    {
+        reduce::reduce_or(self)
+    }
+
+    /// Returns the bitwise XOR of all lanes.
+    #[inline]
+    #[must_use]
+    pub const fn reduce_xor(self) -> T
+    where
+        T: ~const Element,
+        T: ~const BitXor<Output = T>,
+    {
+        reduce::reduce_xor(self)
+    }
+
+    /// Tests if each lane is equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_eq(self, other: Simd<T, LANES>) -> Mask<LANES>
+    where
+        T: ~const Element,
+        T: ~const PartialEq,
+    {
+        cmp::eq(self, other)
+    }
+
+    /// Tests if each lane is not equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_ne(self, other: Simd<T, LANES>) -> Mask<LANES>
+    where
+        T: ~const Element,
+        T: ~const PartialEq,
+    {
+        cmp::ne(self, other)
+    }
+
+    /// Tests if each lane is less than the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_lt(self, other: Simd<T, LANES>) -> Mask<LANES>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+    {
+        cmp::lt(self, other)
+    }
+
+    /// Tests if each lane is less than or equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_le(self, other: Simd<T, LANES>) -> Mask<LANES>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+    {
+        cmp::le(self, other)
+    }
+
+    /// Tests if each lane is greater than the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_gt(self, other: Simd<T, LANES>) -> Mask<LANES>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+    {
+        cmp::gt(self, other)
+    }
+
+    /// Tests if each lane is greater than or equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_ge(self, other: Simd<T, LANES>) -> Mask<LANES>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+    {
+        cmp::ge(self, other)
+    }
+
+    /// Lane-wise fused multiply-add. Computes `(self * a) + b`.
+    ///
+    /// For floats, each lane is rounded once, and the result is the same in const, and non-const
+    /// contexts.
+    #[inline]
+    #[must_use]
+    pub const fn mul_add(self, a: Simd<T, LANES>, b: Simd<T, LANES>) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        fma::mul_add(self, a, b)
+    }
+
+    /// Lane-wise minimum of `self` and `other`.
+    ///
+    /// For floats, if one of the lanes is NaN, then the other lane is returned.
+    #[inline]
+    #[must_use]
+    pub const fn min(self, other: Simd<T, LANES>) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        minmax::min(self, other)
+    }
+
+    /// Lane-wise maximum of `self` and `other`.
+    ///
+    /// For floats, if one of the lanes is NaN, then the other lane is returned.
+    #[inline]
+    #[must_use]
+    pub const fn max(self, other: Simd<T, LANES>) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        minmax::max(self, other)
+    }
+
+    /// Lane-wise absolute value.
+    ///
+    /// Signed integers wrap on overflow, same as `wrapping_abs`.
+    #[inline]
+    #[must_use]
+    pub const fn abs(self) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        minmax::abs(self)
+    }
+}
+
+impl<T, const LANES: usize> const Deref for Simd<T, LANES> {
+    type Target = [T; LANES];
+
+    fn deref(&self) -> &[T; LANES] {
+        // SAFETY: lanes are laid out the same as an array
+        unsafe { &*(self as *const Simd<T, LANES>).cast::<[T; LANES]>() }
+    }
+}
+
+impl<T, const LANES: usize> const DerefMut for Simd<T, LANES> {
+    fn deref_mut(&mut self) -> &mut [T; LANES] {
+        // SAFETY: lanes are laid out the same as an array
+        unsafe { &mut *(self as *mut Simd<T, LANES>).cast::<[T; LANES]>() }
+    }
+}
+
+macro_rules! impl_op {
+    { $trait:ident, $trait_assign:ident, $fn:ident, $fn_assign:ident, $fn_scalar:ident, $fn_simd:ident, $kernel:path, $op:tt } => {
+        impl<T, const LANES: usize> const $trait for Simd<T, LANES>
+        where
+            T: ~const Element,
+            T: ~const $trait<Output = T>,
+        {
+            type Output = Simd<T, LANES>;
+
+            #[inline]
+            #[must_use]
+            fn $fn(self, other: Simd<T, LANES>) -> Simd<T, LANES> {
+                // called in const contexts
+                #[inline]
+                #[must_use]
+                const fn $fn_scalar<T, const LANES: usize>(
+                    a: Simd<T, LANES>,
+                    b: Simd<T, LANES>,
+                ) -> Simd<T, LANES>
+                where
+                    T: ~const Element,
+                    T: ~const $trait<Output = T>,
+                {
+                    let mut a = a.to_array();
+                    let b = b.to_array();
+                    let mut i = 0;
+
+                    while i < LANES {
+                        a[i] = a[i] $op b[i];
+                        i += 1;
+                    }
+
+                    Simd::from_array(a)
+                }
+
+                // called in non-const contexts
+                #[inline]
+                #[must_use]
+                #[cfg_attr(feature = "scalar", allow(dead_code))]
+                fn $fn_simd<T, const LANES: usize>(
+                    a: Simd<T, LANES>,
+                    b: Simd<T, LANES>,
+                ) -> Simd<T, LANES>
+                where
+                    T: Element,
+                    T: $trait<Output = T>,
+                {
+                    unsafe {
+                        Simd::from_array($kernel(a.to_array(), b.to_array()))
+                    }
+                }
+
+                unsafe { const_eval_select!((self, other), $fn_scalar, $fn_simd) }
+            }
+        }
+
+        impl<T, const LANES: usize> const $trait_assign for Simd<T, LANES>
+        where
+            T: ~const Element,
+            T: ~const $trait<Output = T>,
+        {
+            #[inline]
+            #[must_use]
+            fn $fn_assign(&mut self, other: Simd<T, LANES>) {
+                *self = *self $op other;
+            }
+        }
+    }
+}
+
+impl_op! { Add, AddAssign, add, add_assign, scalar_add, simd_add, intrinsics::simd_add, + }
+impl_op! { Div, DivAssign, div, div_assign, scalar_div, simd_div, <T as Kernel>::simd_div, / }
+impl_op! { Mul, MulAssign, mul, mul_assign, scalar_mul, simd_mul, intrinsics::simd_mul, * }
+impl_op! { Rem, RemAssign, rem, rem_assign, scalar_rem, simd_rem, <T as Kernel>::simd_rem, % }
+impl_op! { Sub, SubAssign, sub, sub_assign, scalar_sub, simd_sub, intrinsics::simd_sub, - }
+
+impl<T, const LANES: usize> fmt::Debug for Simd<T, LANES>
+where
+    T: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, fmt)
+    }
+}
@@ -0,0 +1,62 @@
+use super::Simd;
+use crate::intrinsics;
+use crate::intrinsics::const_eval_select;
+use crate::vec::{Element, Mask};
+use core::cmp::{PartialEq, PartialOrd};
+
+macro_rules! impl_cmp {
+    { $fn:ident, $fn_scalar:ident, $fn_simd:ident, $trait:ident, $op:tt } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const LANES: usize>(a: Simd<T, LANES>, b: Simd<T, LANES>) -> Mask<LANES>
+        where
+            T: ~const Element,
+            T: ~const $trait,
+        {
+            // called in const contexts
+            #[inline]
+            #[must_use]
+            const fn $fn_scalar<T, const LANES: usize>(
+                a: Simd<T, LANES>,
+                b: Simd<T, LANES>,
+            ) -> Mask<LANES>
+            where
+                T: ~const Element,
+                T: ~const $trait,
+            {
+                let a = a.to_array();
+                let b = b.to_array();
+                let mut mask = [false; LANES];
+                let mut i = 0;
+
+                while i < LANES {
+                    mask[i] = a[i] $op b[i];
+                    i += 1;
+                }
+
+                Mask::from_array(mask)
+            }
+
+            // called in non-const contexts
+            #[inline]
+            #[must_use]
+            #[cfg_attr(feature = "scalar", allow(dead_code))]
+            fn $fn_simd<T, const LANES: usize>(a: Simd<T, LANES>, b: Simd<T, LANES>) -> Mask<LANES>
+            where
+                T: Element,
+                T: $trait,
+            {
+                unsafe { Mask::from_array(intrinsics::$fn_simd(a.to_array(), b.to_array())) }
+            }
+
+            unsafe { const_eval_select!((a, b), $fn_scalar, $fn_simd) }
+        }
+    }
+}
+
+impl_cmp! { eq, scalar_eq, simd_eq, PartialEq, == }
+impl_cmp! { ne, scalar_ne, simd_ne, PartialEq, != }
+impl_cmp! { lt, scalar_lt, simd_lt, PartialOrd, < }
+impl_cmp! { le, scalar_le, simd_le, PartialOrd, <= }
+impl_cmp! { gt, scalar_gt, simd_gt, PartialOrd, > }
+impl_cmp! { ge, scalar_ge, simd_ge, PartialOrd, >= }
@@ -0,0 +1,61 @@
+use super::Simd;
+use crate::intrinsics::const_eval_select;
+use crate::vec::{Element, Kernel, Sealed};
+
+#[inline]
+#[must_use]
+pub const fn mul_add<T, const LANES: usize>(
+    a: Simd<T, LANES>,
+    b: Simd<T, LANES>,
+    c: Simd<T, LANES>,
+) -> Simd<T, LANES>
+where
+    T: ~const Element,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_mul_add<T, const LANES: usize>(
+        a: Simd<T, LANES>,
+        b: Simd<T, LANES>,
+        c: Simd<T, LANES>,
+    ) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        let mut a = a.to_array();
+        let b = b.to_array();
+        let c = c.to_array();
+        let mut i = 0;
+
+        while i < LANES {
+            a[i] = <T as Sealed>::lane_mul_add(a[i], b[i], c[i]);
+            i += 1;
+        }
+
+        Simd::from_array(a)
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_mul_add<T, const LANES: usize>(
+        a: Simd<T, LANES>,
+        b: Simd<T, LANES>,
+        c: Simd<T, LANES>,
+    ) -> Simd<T, LANES>
+    where
+        T: Element,
+    {
+        unsafe {
+            Simd::from_array(<T as Kernel>::simd_mul_add(
+                a.to_array(),
+                b.to_array(),
+                c.to_array(),
+            ))
+        }
+    }
+
+    unsafe { const_eval_select!((a, b, c), scalar_mul_add, simd_mul_add) }
+}
@@ -0,0 +1,90 @@
+use super::Simd;
+use crate::intrinsics::const_eval_select;
+use crate::vec::{Element, Kernel, Sealed};
+
+macro_rules! impl_minmax {
+    { $fn:ident, $fn_scalar:ident, $fn_simd:ident, $fn_lane:ident } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const LANES: usize>(a: Simd<T, LANES>, b: Simd<T, LANES>) -> Simd<T, LANES>
+        where
+            T: ~const Element,
+        {
+            // called in const contexts
+            #[inline]
+            #[must_use]
+            const fn $fn_scalar<T, const LANES: usize>(
+                a: Simd<T, LANES>,
+                b: Simd<T, LANES>,
+            ) -> Simd<T, LANES>
+            where
+                T: ~const Element,
+            {
+                let mut a = a.to_array();
+                let b = b.to_array();
+                let mut i = 0;
+
+                while i < LANES {
+                    a[i] = <T as Sealed>::$fn_lane(a[i], b[i]);
+                    i += 1;
+                }
+
+                Simd::from_array(a)
+            }
+
+            // called in non-const contexts
+            #[inline]
+            #[must_use]
+            #[cfg_attr(feature = "scalar", allow(dead_code))]
+            fn $fn_simd<T, const LANES: usize>(a: Simd<T, LANES>, b: Simd<T, LANES>) -> Simd<T, LANES>
+            where
+                T: Element,
+            {
+                unsafe { Simd::from_array(<T as Kernel>::$fn_simd(a.to_array(), b.to_array())) }
+            }
+
+            unsafe { const_eval_select!((a, b), $fn_scalar, $fn_simd) }
+        }
+    }
+}
+
+impl_minmax! { min, scalar_min, simd_min, lane_min }
+impl_minmax! { max, scalar_max, simd_max, lane_max }
+
+#[inline]
+#[must_use]
+pub const fn abs<T, const LANES: usize>(simd: Simd<T, LANES>) -> Simd<T, LANES>
+where
+    T: ~const Element,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_abs<T, const LANES: usize>(simd: Simd<T, LANES>) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        let mut array = simd.to_array();
+        let mut i = 0;
+
+        while i < LANES {
+            array[i] = <T as Sealed>::lane_abs(array[i]);
+            i += 1;
+        }
+
+        Simd::from_array(array)
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_abs<T, const LANES: usize>(simd: Simd<T, LANES>) -> Simd<T, LANES>
+    where
+        T: Element,
+    {
+        unsafe { Simd::from_array(<T as Kernel>::simd_abs(simd.to_array())) }
+    }
+
+    unsafe { const_eval_select!((simd,), scalar_abs, simd_abs) }
+}
@@ -0,0 +1,73 @@
+use super::Simd;
+use crate::identity::One;
+use crate::intrinsics;
+use crate::intrinsics::const_eval_select;
+use crate::vec::Element;
+use core::ops::Mul;
+
+// called in const contexts, lanes are multiplied in order
+#[inline]
+#[must_use]
+const fn scalar_product<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+where
+    T: ~const Element,
+    T: ~const One,
+    T: ~const Mul<Output = T>,
+{
+    let array = simd.to_array();
+    let mut output = <T as One>::one();
+    let mut i = 0;
+
+    while i < LANES {
+        output = output * array[i];
+        i += 1;
+    }
+
+    output
+}
+
+#[inline]
+#[must_use]
+pub const fn product<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+where
+    T: ~const Element,
+    T: ~const One,
+    T: ~const Mul<Output = T>,
+{
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_product<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+    where
+        T: Element,
+        T: One,
+        T: Mul<Output = T>,
+    {
+        unsafe { intrinsics::simd_product(simd.to_array()) }
+    }
+
+    unsafe { const_eval_select!((simd,), scalar_product, simd_product) }
+}
+
+#[inline]
+#[must_use]
+pub const fn product_unordered<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+where
+    T: ~const Element,
+    T: ~const One,
+    T: ~const Mul<Output = T>,
+{
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_product_unordered<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+    where
+        T: Element,
+        T: One,
+        T: Mul<Output = T>,
+    {
+        unsafe { intrinsics::simd_product_unordered(simd.to_array()) }
+    }
+
+    unsafe { const_eval_select!((simd,), scalar_product, simd_product_unordered) }
+}
@@ -0,0 +1,62 @@
+use super::Simd;
+use crate::intrinsics;
+use crate::intrinsics::const_eval_select;
+use crate::vec::{Element, Sealed};
+use core::cmp::PartialOrd;
+use core::ops::{BitAnd, BitOr, BitXor};
+
+macro_rules! impl_reduce {
+    {
+        $fn:ident, $fn_scalar:ident, $fn_simd:ident, $trait:ident $(<Output = $output:ident>)?,
+        |$a:ident, $b:ident| $combine:expr
+    } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+        where
+            T: ~const Element,
+            T: ~const $trait $(<Output = $output>)?,
+        {
+            #[inline]
+            #[must_use]
+            const fn $fn_scalar<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+            where
+                T: ~const Element,
+                T: ~const $trait $(<Output = $output>)?,
+            {
+                let array = simd.to_array();
+                let mut output = array[0];
+                let mut i = 1;
+
+                while i < LANES {
+                    let $a = output;
+                    let $b = array[i];
+
+                    output = $combine;
+                    i += 1;
+                }
+
+                output
+            }
+
+            #[inline]
+            #[must_use]
+            #[cfg_attr(feature = "scalar", allow(dead_code))]
+            fn $fn_simd<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+            where
+                T: Element,
+                T: $trait $(<Output = $output>)?,
+            {
+                unsafe { intrinsics::$fn_simd(simd.to_array()) }
+            }
+
+            unsafe { const_eval_select!((simd,), $fn_scalar, $fn_simd) }
+        }
+    }
+}
+
+impl_reduce! { min_element, scalar_min_element, simd_reduce_min, PartialOrd, |a, b| <T as Sealed>::lane_min(a, b) }
+impl_reduce! { max_element, scalar_max_element, simd_reduce_max, PartialOrd, |a, b| <T as Sealed>::lane_max(a, b) }
+impl_reduce! { reduce_and, scalar_reduce_and, simd_reduce_and, BitAnd<Output = T>, |a, b| a & b }
+impl_reduce! { reduce_or, scalar_reduce_or, simd_reduce_or, BitOr<Output = T>, |a, b| a | b }
+impl_reduce! { reduce_xor, scalar_reduce_xor, simd_reduce_xor, BitXor<Output = T>, |a, b| a ^ b }
@@ -0,0 +1,295 @@
+use super::{Simd, MAX_LANES};
+use crate::intrinsics::{self, const_eval_select, ShuffleIndex};
+use crate::vec::Element;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+/// Indices given as a const generic.
+pub(crate) struct Const<const M: usize, const INDEX: [usize; M]>;
+
+impl<const M: usize, const INDEX: [usize; M]> ShuffleIndex<M> for Const<M, INDEX> {
+    const INDEX: [usize; M] = INDEX;
+}
+
+/// Rotate lanes left by `OFFSET`.
+pub(crate) struct RotateLeft<const N: usize, const OFFSET: usize>;
+
+impl<const N: usize, const OFFSET: usize> ShuffleIndex<N> for RotateLeft<N, OFFSET> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            index[i] = (i + OFFSET % N) % N;
+            i += 1;
+        }
+
+        index
+    };
+}
+
+/// Rotate lanes right by `OFFSET`.
+pub(crate) struct RotateRight<const N: usize, const OFFSET: usize>;
+
+impl<const N: usize, const OFFSET: usize> ShuffleIndex<N> for RotateRight<N, OFFSET> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            index[i] = (i + N - OFFSET % N) % N;
+            i += 1;
+        }
+
+        index
+    };
+}
+
+/// Reverse the order of lanes.
+pub(crate) struct Reverse<const N: usize>;
+
+impl<const N: usize> ShuffleIndex<N> for Reverse<N> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            index[i] = N - 1 - i;
+            i += 1;
+        }
+
+        index
+    };
+}
+
+/// Interleave lanes of both inputs, starting at lane `OFFSET` of the interleaved output.
+pub(crate) struct Interleave<const N: usize, const OFFSET: usize>;
+
+impl<const N: usize, const OFFSET: usize> ShuffleIndex<N> for Interleave<N, OFFSET> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            let j = i + OFFSET;
+
+            index[i] = if j % 2 == 0 { j / 2 } else { N + j / 2 };
+            i += 1;
+        }
+
+        index
+    };
+}
+
+/// Every second lane of both inputs, starting at lane `OFFSET` of the first input.
+pub(crate) struct Deinterleave<const N: usize, const OFFSET: usize>;
+
+impl<const N: usize, const OFFSET: usize> ShuffleIndex<N> for Deinterleave<N, OFFSET> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            index[i] = i * 2 + OFFSET;
+            i += 1;
+        }
+
+        index
+    };
+}
+
+/// Fails compilation if any index of `I` is not within `0..N * INPUTS`.
+pub(crate) struct Validate<I, const M: usize, const N: usize, const INPUTS: usize>(PhantomData<I>);
+
+impl<I, const M: usize, const N: usize, const INPUTS: usize> Validate<I, M, N, INPUTS>
+where
+    I: ShuffleIndex<M>,
+{
+    pub(crate) const VALID: () = {
+        let mut i = 0;
+
+        while i < M {
+            if I::INDEX[i] >= N * INPUTS {
+                panic!("shuffle index out of range");
+            }
+
+            i += 1;
+        }
+    };
+}
+
+/// Fails compilation if `N` is not within `1..=MAX_LANES`, as a [`Simd`] of `N` lanes would not
+/// have a layout.
+pub(crate) struct ValidateLanes<const N: usize>;
+
+impl<const N: usize> ValidateLanes<N> {
+    pub(crate) const VALID: () = {
+        if N == 0 || N > MAX_LANES {
+            panic!("shuffled vectors must have between 1 and 32768 lanes");
+        }
+    };
+}
+
+/// Selects lanes from the concatenation of `a` and `b`.
+#[inline]
+#[must_use]
+pub(crate) const fn shuffle<T, I, const N: usize, const M: usize>(
+    a: Simd<T, N>,
+    b: Simd<T, N>,
+) -> Simd<T, M>
+where
+    T: ~const Element,
+    I: ShuffleIndex<M>,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_shuffle<T, I, const N: usize, const M: usize>(
+        a: Simd<T, N>,
+        b: Simd<T, N>,
+    ) -> Simd<T, M>
+    where
+        T: ~const Element,
+        I: ShuffleIndex<M>,
+    {
+        let a = a.to_array();
+        let b = b.to_array();
+        let mut output = MaybeUninit::<[T; M]>::uninit();
+        let ptr = output.as_mut_ptr().cast::<T>();
+        let mut i = 0;
+
+        while i < M {
+            let index = I::INDEX[i];
+            let element = if index < N { a[index] } else { b[index - N] };
+
+            // SAFETY: `i` is within `0..M`
+            unsafe {
+                ptr.add(i).write(element);
+            }
+
+            i += 1;
+        }
+
+        // SAFETY: every lane was written above
+        Simd::from_array(unsafe { output.assume_init() })
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_shuffle<T, I, const N: usize, const M: usize>(
+        a: Simd<T, N>,
+        b: Simd<T, N>,
+    ) -> Simd<T, M>
+    where
+        T: Element,
+        I: ShuffleIndex<M>,
+    {
+        unsafe {
+            Simd::from_array(intrinsics::simd_shuffle::<T, I, N, M>(
+                a.to_array(),
+                b.to_array(),
+            ))
+        }
+    }
+
+    #[allow(clippy::let_unit_value)]
+    let () = Validate::<I, M, N, 2>::VALID;
+
+    unsafe {
+        const_eval_select!(
+            (a, b),
+            scalar_shuffle::<T, I, N, M>,
+            simd_shuffle::<T, I, N, M>,
+        )
+    }
+}
+
+impl<T, const LANES: usize> Simd<T, LANES> {
+    /// Creates a new SIMD vector from the lanes of `self` at `INDEX`.
+    ///
+    /// Indices are checked at compile time to be within `0..LANES`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Simd;
+    ///
+    /// let simd = Simd::from_array([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(*simd.shuffle::<2, { [3, 0] }>(), [4, 1]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn shuffle<const M: usize, const INDEX: [usize; M]>(self) -> Simd<T, M>
+    where
+        T: ~const Element,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Validate::<Const<M, INDEX>, M, LANES, 1>::VALID;
+
+        shuffle::<T, Const<M, INDEX>, LANES, M>(self, self)
+    }
+
+    /// Rotates lanes left by `OFFSET`, such that lane `OFFSET` becomes the first lane.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_lanes_left<const OFFSET: usize>(self) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        shuffle::<T, RotateLeft<LANES, OFFSET>, LANES, LANES>(self, self)
+    }
+
+    /// Rotates lanes right by `OFFSET`, such that the first lane becomes lane `OFFSET`.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_lanes_right<const OFFSET: usize>(self) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        shuffle::<T, RotateRight<LANES, OFFSET>, LANES, LANES>(self, self)
+    }
+
+    /// Reverses the order of lanes.
+    #[inline]
+    #[must_use]
+    pub const fn reverse(self) -> Simd<T, LANES>
+    where
+        T: ~const Element,
+    {
+        shuffle::<T, Reverse<LANES>, LANES, LANES>(self, self)
+    }
+
+    /// Interleaves lanes of `self` and `other`.
+    ///
+    /// The first SIMD vector holds the low half of the interleaved lanes, and the second holds
+    /// the high half.
+    #[inline]
+    #[must_use]
+    pub const fn interleave(self, other: Simd<T, LANES>) -> (Simd<T, LANES>, Simd<T, LANES>)
+    where
+        T: ~const Element,
+    {
+        (
+            shuffle::<T, Interleave<LANES, 0>, LANES, LANES>(self, other),
+            shuffle::<T, Interleave<LANES, LANES>, LANES, LANES>(self, other),
+        )
+    }
+
+    /// Deinterleaves lanes of `self` and `other`, the inverse of [`interleave`](Self::interleave).
+    ///
+    /// The first SIMD vector holds the even lanes, and the second holds the odd lanes.
+    #[inline]
+    #[must_use]
+    pub const fn deinterleave(self, other: Simd<T, LANES>) -> (Simd<T, LANES>, Simd<T, LANES>)
+    where
+        T: ~const Element,
+    {
+        (
+            shuffle::<T, Deinterleave<LANES, 0>, LANES, LANES>(self, other),
+            shuffle::<T, Deinterleave<LANES, 1>, LANES, LANES>(self, other),
+        )
+    }
+}
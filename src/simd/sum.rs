@@ -0,0 +1,73 @@
+use super::Simd;
+use crate::identity::Zero;
+use crate::intrinsics;
+use crate::intrinsics::const_eval_select;
+use crate::vec::Element;
+use core::ops::Add;
+
+// called in const contexts, lanes are added in order
+#[inline]
+#[must_use]
+const fn scalar_sum<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+where
+    T: ~const Element,
+    T: ~const Zero,
+    T: ~const Add<Output = T>,
+{
+    let array = simd.to_array();
+    let mut output = <T as Zero>::zero();
+    let mut i = 0;
+
+    while i < LANES {
+        output = output + array[i];
+        i += 1;
+    }
+
+    output
+}
+
+#[inline]
+#[must_use]
+pub const fn sum<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+where
+    T: ~const Element,
+    T: ~const Zero,
+    T: ~const Add<Output = T>,
+{
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_sum<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+    where
+        T: Element,
+        T: Zero,
+        T: Add<Output = T>,
+    {
+        unsafe { intrinsics::simd_sum(simd.to_array()) }
+    }
+
+    unsafe { const_eval_select!((simd,), scalar_sum, simd_sum) }
+}
+
+#[inline]
+#[must_use]
+pub const fn sum_unordered<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+where
+    T: ~const Element,
+    T: ~const Zero,
+    T: ~const Add<Output = T>,
+{
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_sum_unordered<T, const LANES: usize>(simd: Simd<T, LANES>) -> T
+    where
+        T: Element,
+        T: Zero,
+        T: Add<Output = T>,
+    {
+        unsafe { intrinsics::simd_sum_unordered(simd.to_array()) }
+    }
+
+    unsafe { const_eval_select!((simd,), scalar_sum, simd_sum_unordered) }
+}
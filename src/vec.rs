@@ -1,30 +1,127 @@
 use crate::identity::{One, Zero};
-use crate::Real;
+use crate::{ParseError, Real, Simd};
+use core::array::TryFromSliceError;
+use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
 use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
 use core::ops::{Add, Div, Mul, Rem, Sub};
+use core::ops::{BitAnd, BitOr, BitXor};
 use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
 
+pub use cast::Cast;
 pub use element::Element;
+pub(crate) use element::{Kernel, Sealed};
+pub(crate) use float::Float;
+pub use into_iter::IntoIter;
 pub use iter::Iter;
 pub use iter_mut::IterMut;
 pub use lanes::{LaneCount, Lanes};
+pub use mask::Mask;
 
+mod approx_eq;
+mod cast;
+mod cmp;
+#[cfg(feature = "runtime-dispatch")]
+mod dispatch;
+mod fma;
+mod map;
+mod math;
+mod minmax;
 mod product;
+mod reduce;
+mod resize;
+mod shuffle;
 mod sum;
 
 mod element;
+// unused with the `scalar` feature
+#[cfg_attr(feature = "scalar", allow(dead_code))]
+mod float;
+mod into_iter;
 mod iter;
 mod iter_mut;
 mod lanes;
+mod mask;
 
 /// Generic arbitary length vector.
+///
+/// Lanes are processed in chunks of [`LANES`](Self::LANES) lanes as a [`Simd`], and the remaining
+/// lanes one at a time.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Vec<T, const N: usize> {
     array: [T; N],
 }
 
+impl<T, const N: usize> Vec<T, N>
+where
+    Lanes<T, N>: LaneCount,
+{
+    /// Number of lanes processed at once, chosen for the target, see [`LaneCount`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// assert_eq!(Vec::<f32, 3>::LANES, 2);
+    /// assert_eq!(Vec::<f32, 1>::LANES, 1);
+    /// ```
+    pub const LANES: usize = <Lanes<T, N> as LaneCount>::LANES;
+
+    /// Number of whole chunks of `LANES` lanes, the remaining lanes start at `CHUNKS * LANES`.
+    const CHUNKS: usize = N / <Lanes<T, N> as LaneCount>::LANES;
+}
+
+impl<T, const N: usize> Vec<T, N>
+where
+    Lanes<T, N>: LaneCount,
+    [(); <Lanes<T, N> as LaneCount>::LANES]:,
+{
+    /// Reads chunk `index` of `LANES` lanes.
+    #[inline]
+    #[must_use]
+    const fn chunk(&self, index: usize) -> Simd<T, { <Lanes<T, N> as LaneCount>::LANES }>
+    where
+        T: Copy,
+    {
+        debug_assert!(index < Self::CHUNKS);
+
+        // SAFETY: chunks within `0..CHUNKS` are within the array
+        unsafe {
+            let chunk = (&self.array as *const [T; N])
+                .cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>()
+                .add(index)
+                .read();
+
+            Simd::from_array(chunk)
+        }
+    }
+
+    /// Writes chunk `index` of `LANES` lanes.
+    #[inline]
+    const fn set_chunk(
+        &mut self,
+        index: usize,
+        chunk: Simd<T, { <Lanes<T, N> as LaneCount>::LANES }>,
+    ) where
+        T: Copy,
+    {
+        debug_assert!(index < Self::CHUNKS);
+
+        // SAFETY: chunks within `0..CHUNKS` are within the array
+        unsafe {
+            (&mut self.array as *mut [T; N])
+                .cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>()
+                .add(index)
+                .write(chunk.to_array());
+        }
+    }
+}
+
 impl<T, const N: usize> Vec<T, N> {
     /// Converts an array to a vector.
     #[inline]
@@ -88,11 +185,44 @@ impl<T, const N: usize> Vec<T, N> {
         Self::splat(One::one())
     }
 
+    /// Converts each lane to `U`, with the same semantics as `as`.
+    ///
+    /// Float to integer conversions saturate, and NaN converts to `0`.
+    #[inline]
+    #[must_use]
+    pub const fn cast<U>(self) -> Vec<U, N>
+    where
+        T: ~const Element,
+        T: ~const Cast<U>,
+        U: ~const Element,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        cast::cast(self)
+    }
+
     #[inline]
     pub const fn iter(&self) -> Iter<'_, T, N> {
         Iter::new(self)
     }
 
+    /// Returns an iterator over mutable references to each lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let mut vec = Vec::from_array([1, 2, 3]);
+    /// let mut iter = vec.iter_mut();
+    /// let a = iter.next().unwrap();
+    /// let b = iter.next_back().unwrap();
+    ///
+    /// *a += 10;
+    /// *b += 30;
+    ///
+    /// assert_eq!(*vec, [11, 2, 33]);
+    /// ```
     #[inline]
     pub const fn iter_mut(&mut self) -> IterMut<'_, T, N> {
         IterMut::new(self)
@@ -129,6 +259,25 @@ impl<T, const N: usize> Vec<T, N> {
         (self - other).magnitude_squared()
     }
 
+    /// Dot product of `self` and `other`.
+    ///
+    /// Products are accumulated with a fused multiply-add for floats, [`LANES`](Self::LANES)
+    /// lanes at a time, then the accumulated lanes are summed in order, followed by the
+    /// remainder. Both const, and non-const contexts use this order, so the results are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(const_trait_impl)]
+    ///
+    /// use meth::Vec;
+    ///
+    /// const A: Vec<f32, 9> = Vec::from_array([0.1, 1e8, 0.3, -1e8, 0.5, 0.6, 1e-3, 0.8, 0.9]);
+    /// const B: Vec<f32, 9> = Vec::from_array([1e8, 0.2, -1e8, 0.4, 3.0, 0.7, 1e8, 0.1, 7.0]);
+    /// const DOT: f32 = A.dot(B);
+    ///
+    /// assert_eq!(A.dot(B).to_bits(), DOT.to_bits());
+    /// ```
     #[inline]
     pub const fn dot(self, other: Vec<T, N>) -> T
     where
@@ -140,7 +289,26 @@ impl<T, const N: usize> Vec<T, N> {
         Lanes<T, N>: LaneCount,
         [(); <Lanes<T, N> as LaneCount>::LANES]:,
     {
-        (self * other).sum()
+        fma::dot(self, other)
+    }
+
+    /// Same as [`dot`](Self::dot), but accumulates lanes in an unspecified order.
+    ///
+    /// This allows the reduction to use the widest registers available, at the cost of results
+    /// for floats not being bit-reproducible, between targets, or between const and non-const
+    /// contexts.
+    #[inline]
+    pub const fn dot_unordered(self, other: Vec<T, N>) -> T
+    where
+        T: ~const Element,
+        T: ~const One,
+        T: ~const Zero,
+        T: ~const Add<Output = T>,
+        T: ~const Mul<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        fma::dot_unordered(self, other)
     }
 
     #[inline]
@@ -184,6 +352,22 @@ impl<T, const N: usize> Vec<T, N> {
         product::product(self)
     }
 
+    /// Same as [`product`](Self::product), but multiplies lanes in an unspecified order.
+    ///
+    /// This allows the reduction to be vectorized, at the cost of results for floats not being
+    /// bit-reproducible, between targets, or between const and non-const contexts.
+    #[inline]
+    pub const fn product_unordered(self) -> T
+    where
+        T: ~const Element,
+        T: ~const One,
+        T: ~const Mul<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        product::product_unordered(self)
+    }
+
     #[inline]
     pub const fn sum(self) -> T
     where
@@ -196,145 +380,1241 @@ impl<T, const N: usize> Vec<T, N> {
         sum::sum(self)
     }
 
+    /// Same as [`sum`](Self::sum), but adds lanes in an unspecified order.
+    ///
+    /// This allows the reduction to be vectorized, at the cost of results for floats not being
+    /// bit-reproducible, between targets, or between const and non-const contexts.
     #[inline]
-    #[must_use]
-    pub const fn to_degrees(self) -> Vec<T, N>
+    pub const fn sum_unordered(self) -> T
     where
         T: ~const Element,
-        T: ~const Real,
-        T: ~const Mul<Output = T>,
+        T: ~const Zero,
+        T: ~const Add<Output = T>,
         Lanes<T, N>: LaneCount,
         [(); <Lanes<T, N> as LaneCount>::LANES]:,
     {
-        self * Self::splat(<T as crate::real::Sealed>::_180_PI)
+        sum::sum_unordered(self)
     }
 
+    /// Returns the minimum lane.
+    ///
+    /// For floats, NaN lanes are ignored, unless all lanes are NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
     #[inline]
     #[must_use]
-    pub const fn to_radians(self) -> Vec<T, N>
+    pub const fn min_element(self) -> T
     where
         T: ~const Element,
-        T: ~const Real,
-        T: ~const Mul<Output = T>,
+        T: ~const PartialOrd,
         Lanes<T, N>: LaneCount,
         [(); <Lanes<T, N> as LaneCount>::LANES]:,
     {
-        self * Self::splat(<T as crate::real::Sealed>::_PI_180)
+        reduce::min_element(self)
     }
-}
 
-impl<T, const N: usize> const Deref for Vec<T, N> {
-    type Target = [T; N];
-
-    fn deref(&self) -> &[T; N] {
-        &self.array
+    /// Returns the maximum lane.
+    ///
+    /// For floats, NaN lanes are ignored, unless all lanes are NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    #[inline]
+    #[must_use]
+    pub const fn max_element(self) -> T
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        reduce::max_element(self)
     }
-}
 
-impl<T, const N: usize> const DerefMut for Vec<T, N> {
-    fn deref_mut(&mut self) -> &mut [T; N] {
-        &mut self.array
+    /// Returns the bitwise AND of all lanes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    #[inline]
+    #[must_use]
+    pub const fn reduce_and(self) -> T
+    where
+        T: ~const Element,
+        T: ~const BitAnd<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        reduce::reduce_and(self)
     }
-}
 
-macro_rules! impl_op {
-    { $trait:ident, $trait_assign:ident, $fn:ident, $fn_assign:ident, $fn_scalar:ident, $fn_simd:ident, $op:tt } => {
-        impl<T, const N: usize> const $trait for Vec<T, N>
-        where
-            T: ~const Element,
-            T: ~const $trait<Output = T>,
-            Lanes<T, N>: LaneCount,
-            [(); <Lanes<T, N> as LaneCount>::LANES]:,
-        {
-            type Output = Vec<T, N>;
+    /// Returns the bitwise OR of all lanes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    #[inline]
+    #[must_use]
+    pub const fn reduce_or(self) -> T
+    where
+        T: ~const Element,
+        T: ~const BitOr<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        reduce::reduce_or(self)
+    }
 
-            #[inline]
-            #[must_use]
-            fn $fn(self, other: Vec<T, N>) -> Vec<T, N> {
-                // called in const contexts
-                #[inline]
-                #[must_use]
-                const fn $fn_scalar<T, const N: usize>(mut a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
-                where
-                    T: ~const Element,
-                    T: ~const $trait<Output = T>,
-                    Lanes<T, N>: LaneCount,
-                    [(); Lanes::<T, N>::LANES]:,
-                {
-                    let mut a_iter = a.iter_mut();
-                    let mut b_iter = b.iter();
-
-                    while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
-                        *a = *a $op *b;
-                    }
-
-                    a
-                }
+    /// Returns the bitwise XOR of all lanes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    #[inline]
+    #[must_use]
+    pub const fn reduce_xor(self) -> T
+    where
+        T: ~const Element,
+        T: ~const BitXor<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        reduce::reduce_xor(self)
+    }
 
-                // called in non-const contexts
-                #[inline]
-                #[must_use]
-                fn $fn_simd<T, const N: usize>(mut a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
-                where
-                    T: Element,
-                    T: $trait<Output = T>,
-                    Lanes<T, N>: LaneCount,
-                    [(); Lanes::<T, N>::LANES]:,
-                {
-                    unsafe {
-                        let mut a_iter = a.array.chunks_exact_mut(<Lanes<T, N> as LaneCount>::LANES);
-                        let mut b_iter = b.array.chunks_exact(<Lanes<T, N> as LaneCount>::LANES);
-
-                        while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
-                            let a = a.as_mut_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
-                            let b = b.as_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
-
-                            *a = crate::intrinsics::$fn_simd(*a, *b);
-                        }
-
-                        let mut a_iter = a_iter.into_remainder().iter_mut();
-                        let mut b_iter = b_iter.remainder().iter();
-
-                        while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
-                            *a = *a $op *b;
-                        }
-
-                        a
-                    }
-                }
+    /// Returns the index of the first minimum lane.
+    ///
+    /// For floats, NaN lanes are ignored, same as [`min_element`](Self::min_element), and `0` is
+    /// returned if all lanes are NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([f32::NAN, 3.0, 1.0, f32::NAN, 1.0]);
+    ///
+    /// assert_eq!(vec.argmin(), 2);
+    ///
+    /// let vec = Vec::from_array([f32::NAN; 4]);
+    ///
+    /// assert_eq!(vec.argmin(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn argmin(self) -> usize
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        let minimum = self.min_element();
+        let mut i = 0;
 
-                unsafe { core::intrinsics::const_eval_select((self, other), $fn_scalar, $fn_simd) }
+        while i < N {
+            if self.array[i] == minimum {
+                return i;
             }
+
+            i += 1;
         }
 
-        impl<T, const N: usize> const $trait_assign for Vec<T, N>
-        where
-            T: ~const Element,
-            T: ~const $trait <Output = T>,
-            Lanes<T, N>: LaneCount,
-            [(); <Lanes<T, N> as LaneCount>::LANES]:,
-        {
-            #[inline]
-            #[must_use]
-            fn $fn_assign(&mut self, other: Vec<T, N>) {
-                *self = *self $op other;
+        // all lanes are NaN
+        0
+    }
+
+    /// Returns the index of the first maximum lane.
+    ///
+    /// For floats, NaN lanes are ignored, same as [`max_element`](Self::max_element), and `0` is
+    /// returned if all lanes are NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([f32::NAN, 1.0, 3.0, f32::NAN, 3.0]);
+    ///
+    /// assert_eq!(vec.argmax(), 2);
+    ///
+    /// let vec = Vec::from_array([f32::NAN; 4]);
+    ///
+    /// assert_eq!(vec.argmax(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn argmax(self) -> usize
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        let maximum = self.max_element();
+        let mut i = 0;
+
+        while i < N {
+            if self.array[i] == maximum {
+                return i;
             }
+
+            i += 1;
         }
+
+        // all lanes are NaN
+        0
+    }
+
+    /// Tests if each lane is equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_eq(self, other: Vec<T, N>) -> Mask<N>
+    where
+        T: ~const Element,
+        T: ~const PartialEq,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        cmp::eq(self, other)
     }
-}
 
-impl_op! { Add, AddAssign, add, add_assign, scalar_add, simd_add, + }
-impl_op! { Div, DivAssign, div, div_assign, scalar_div, simd_div, / }
-impl_op! { Mul, MulAssign, mul, mul_assign, scalar_mul, simd_mul, * }
-impl_op! { Rem, RemAssign, rem, rem_assign, scalar_rem, simd_rem, % }
-impl_op! { Sub, SubAssign, sub, sub_assign, scalar_sub, simd_sub, - }
+    /// Tests if each lane is not equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_ne(self, other: Vec<T, N>) -> Mask<N>
+    where
+        T: ~const Element,
+        T: ~const PartialEq,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        cmp::ne(self, other)
+    }
 
-impl<T, const LEN: usize> fmt::Debug for Vec<T, LEN>
-where
-    T: fmt::Debug,
-{
+    /// Tests if each lane is less than the corresponding lane in `other`.
     #[inline]
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(&self.array, fmt)
+    #[must_use]
+    pub const fn lanes_lt(self, other: Vec<T, N>) -> Mask<N>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        cmp::lt(self, other)
+    }
+
+    /// Tests if each lane is less than or equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_le(self, other: Vec<T, N>) -> Mask<N>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        cmp::le(self, other)
+    }
+
+    /// Tests if each lane is greater than the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_gt(self, other: Vec<T, N>) -> Mask<N>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        cmp::gt(self, other)
+    }
+
+    /// Tests if each lane is greater than or equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn lanes_ge(self, other: Vec<T, N>) -> Mask<N>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        cmp::ge(self, other)
+    }
+
+    /// Lane-wise fused multiply-add. Computes `(self * a) + b`.
+    ///
+    /// For floats, each lane is rounded once, and the result is the same in const, and non-const
+    /// contexts.
+    #[inline]
+    #[must_use]
+    pub const fn mul_add(self, a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        fma::mul_add(self, a, b)
+    }
+
+    /// Lane-wise minimum of `self` and `other`.
+    ///
+    /// For floats, if one of the lanes is NaN, then the other lane is returned.
+    #[inline]
+    #[must_use]
+    pub const fn min(self, other: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        minmax::min(self, other)
+    }
+
+    /// Lane-wise maximum of `self` and `other`.
+    ///
+    /// For floats, if one of the lanes is NaN, then the other lane is returned.
+    #[inline]
+    #[must_use]
+    pub const fn max(self, other: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        minmax::max(self, other)
+    }
+
+    /// Restrict each lane to the interval of the corresponding lanes in `min` and `max`.
+    ///
+    /// Lanes that are NaN remain NaN, same as [`Real::clamp`].
+    #[inline]
+    #[must_use]
+    pub const fn clamp(self, min: Vec<T, N>, max: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const PartialOrd,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        let vec = self.lanes_lt(min).select(min, self);
+
+        vec.lanes_gt(max).select(max, vec)
+    }
+
+    /// Lane-wise absolute value.
+    ///
+    /// Signed integers wrap on overflow, same as `wrapping_abs`.
+    #[inline]
+    #[must_use]
+    pub const fn abs(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        minmax::abs(self)
+    }
+
+    /// Lane-wise sign of a float.
+    ///
+    /// - `1.0` if the lane is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the lane is negative, `-0.0` or `NEG_INFINITY`
+    /// - NaN if the lane is NaN
+    #[inline]
+    #[must_use]
+    pub const fn signum(mut self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: ~const One,
+        T: ~const PartialEq,
+    {
+        let mut iter = self.iter_mut();
+
+        while let Some(element) = iter.next() {
+            #[allow(clippy::eq_op)]
+            if *element == *element {
+                *element = <T as Real>::copysign(One::one(), *element);
+            }
+        }
+
+        self
+    }
+
+    /// Lane-wise magnitude of `self` with the sign of the corresponding lane in `sign`.
+    #[inline]
+    #[must_use]
+    pub const fn copysign(mut self, sign: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+    {
+        let mut a_iter = self.iter_mut();
+        let mut b_iter = sign.iter();
+
+        while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
+            *a = <T as Real>::copysign(*a, *b);
+        }
+
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_degrees(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: ~const Mul<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        self * Self::splat(<T as crate::real::Sealed>::_180_PI)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_radians(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: ~const Mul<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        self * Self::splat(<T as crate::real::Sealed>::_PI_180)
+    }
+
+    /// Lane-wise square root.
+    ///
+    /// NaN for negative lanes, except `-0.0`.
+    #[inline]
+    #[must_use]
+    pub const fn sqrt(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::sqrt(self)
+    }
+
+    /// Lane-wise reciprocal square root, `1 / sqrt(x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..20_000 {
+    ///     let x = [1, 2, 3, 4, 5].map(|j| (i * 5 + j) as f32 * 0.01);
+    ///     let rsqrt = Vec::<f32, 5>::from_array(x).rsqrt();
+    ///
+    ///     for j in 0..5 {
+    ///         assert_eq!(rsqrt[j], Real::recip(Real::sqrt(x[j])));
+    ///     }
+    /// }
+    ///
+    /// let x = Vec::from_array([0.0_f32, f32::INFINITY, -1.0, f32::NAN, 1e-40]).rsqrt();
+    ///
+    /// assert_eq!(x[0], f32::INFINITY);
+    /// assert_eq!(x[1], 0.0);
+    /// assert!(x[2].is_nan());
+    /// assert!(x[3].is_nan());
+    /// assert_eq!(x[4], Real::recip(Real::sqrt(1e-40_f32)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rsqrt(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::rsqrt(self)
+    }
+
+    /// Lane-wise reciprocal, `1 / x`.
+    #[inline]
+    #[must_use]
+    pub const fn recip(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::recip(self)
+    }
+
+    /// Lane-wise largest integer less than or equal to each lane.
+    #[inline]
+    #[must_use]
+    pub const fn floor(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::floor(self)
+    }
+
+    /// Lane-wise smallest integer greater than or equal to each lane.
+    #[inline]
+    #[must_use]
+    pub const fn ceil(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::ceil(self)
+    }
+
+    /// Lane-wise nearest integer, half-way cases are rounded away from `0.0`.
+    #[inline]
+    #[must_use]
+    pub const fn round(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::round(self)
+    }
+
+    /// Lane-wise sine, in radians.
+    ///
+    /// At runtime, lanes are reduced to `[-π/4, π/4]`, and evaluated with a polynomial, so
+    /// results may differ from [`Real::sin`] in the last few bits, and accuracy degrades for
+    /// very large lanes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..40_000 {
+    ///     let x = [0, 1, 2, 3, 4].map(|j| (i * 5 + j) as f32 * 0.01 - 1000.0);
+    ///     let sin = Vec::<f32, 5>::from_array(x).sin();
+    ///
+    ///     for j in 0..5 {
+    ///         assert!((sin[j] - Real::sin(x[j])).abs() < 2.5e-7);
+    ///     }
+    /// }
+    ///
+    /// let x = Vec::from_array([f32::NAN, f32::INFINITY, f32::NEG_INFINITY]).sin();
+    ///
+    /// assert!(x.iter().all(|x| x.is_nan()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn sin(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::sin(self)
+    }
+
+    /// Lane-wise cosine, in radians.
+    ///
+    /// Same accuracy as [`sin`](Self::sin).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..40_000 {
+    ///     let x = [0, 1, 2, 3, 4].map(|j| (i * 5 + j) as f32 * 0.01 - 1000.0);
+    ///     let cos = Vec::<f32, 5>::from_array(x).cos();
+    ///
+    ///     for j in 0..5 {
+    ///         assert!((cos[j] - Real::cos(x[j])).abs() < 2.5e-7);
+    ///     }
+    /// }
+    ///
+    /// let x = Vec::from_array([f32::NAN, f32::INFINITY, f32::NEG_INFINITY]).cos();
+    ///
+    /// assert!(x.iter().all(|x| x.is_nan()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cos(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::cos(self)
+    }
+
+    /// Lane-wise sine, and cosine, in radians, computed together.
+    ///
+    /// Same accuracy as [`sin`](Self::sin).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let (sin, cos) = Vec::from_array([0.0_f32, 0.0]).sin_cos();
+    ///
+    /// assert_eq!(*sin, [0.0, 0.0]);
+    /// assert_eq!(*cos, [1.0, 1.0]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn sin_cos(self) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::sin_cos(self)
+    }
+
+    /// Lane-wise tangent, in radians, `sin(x) / cos(x)`.
+    ///
+    /// Same accuracy as [`sin`](Self::sin), relative to the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..600 {
+    ///     let x = [0, 1, 2, 3, 4].map(|j| (i * 5 + j) as f32 * 0.001 - 1.5);
+    ///     let tan = Vec::<f32, 5>::from_array(x).tan();
+    ///
+    ///     for j in 0..5 {
+    ///         let expected = Real::tan(x[j]);
+    ///
+    ///         assert!((tan[j] - expected).abs() <= expected.abs() * 5e-7);
+    ///     }
+    /// }
+    ///
+    /// assert!(Vec::from_array([f32::NAN, f32::INFINITY]).tan().iter().all(|x| x.is_nan()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn tan(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::tan(self)
+    }
+
+    /// Lane-wise exponential function, `e^x`.
+    ///
+    /// At runtime, results are evaluated with a polynomial, and may differ from [`Real::exp`]
+    /// in the last few bits. Results below the smallest normal float are flushed to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..3_400 {
+    ///     let x = [0, 1, 2, 3, 4].map(|j| (i * 5 + j) as f32 * 0.01 - 85.0);
+    ///     let exp = Vec::<f32, 5>::from_array(x).exp();
+    ///
+    ///     for j in 0..5 {
+    ///         let expected = Real::exp(x[j]);
+    ///
+    ///         assert!((exp[j] - expected).abs() <= expected * 2.5e-7);
+    ///     }
+    /// }
+    ///
+    /// let x = [-88.0_f32, 89.0, f32::NEG_INFINITY, f32::INFINITY, f32::NAN];
+    /// let x = Vec::from_array(x).exp();
+    ///
+    /// // `e^-88` is subnormal
+    /// assert!(Real::exp(-88.0_f32) > 0.0);
+    /// assert_eq!(x[0], 0.0);
+    /// assert_eq!(x[1], f32::INFINITY);
+    /// assert_eq!(x[2], 0.0);
+    /// assert_eq!(x[3], f32::INFINITY);
+    /// assert!(x[4].is_nan());
+    ///
+    /// // up to the largest input with a finite result, `ln(MAX)`
+    /// let x = [88.7228_f32, 88.72283, 88.72284];
+    /// let exp = Vec::from_array(x).exp();
+    ///
+    /// assert!((exp[0] - Real::exp(x[0])).abs() <= Real::exp(x[0]) * 2.5e-7);
+    /// assert!((exp[1] - Real::exp(x[1])).abs() <= Real::exp(x[1]) * 2.5e-7);
+    /// assert_eq!(exp[2], f32::INFINITY);
+    ///
+    /// let x = [709.782712893384_f64, 709.7827128933841];
+    /// let exp = Vec::from_array(x).exp();
+    ///
+    /// assert!((exp[0] - Real::exp(x[0])).abs() <= Real::exp(x[0]) * 1e-15);
+    /// assert_eq!(exp[1], f64::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn exp(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::exp(self)
+    }
+
+    /// Lane-wise natural logarithm.
+    ///
+    /// At runtime, results are evaluated with a polynomial, and may differ from [`Real::ln`]
+    /// in the last few bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..20_000 {
+    ///     let x = [1, 2, 3, 4, 5].map(|j| (i * 5 + j) as f32 * 0.01);
+    ///     let ln = Vec::<f32, 5>::from_array(x).ln();
+    ///
+    ///     for j in 0..5 {
+    ///         let expected = Real::ln(x[j]);
+    ///
+    ///         assert!((ln[j] - expected).abs() <= expected.abs().max(1.0) * 2.5e-7);
+    ///     }
+    /// }
+    ///
+    /// let x = Vec::from_array([0.0_f32, -1.0, f32::INFINITY, f32::NAN, 1e-40]).ln();
+    ///
+    /// assert_eq!(x[0], f32::NEG_INFINITY);
+    /// assert!(x[1].is_nan());
+    /// assert_eq!(x[2], f32::INFINITY);
+    /// assert!(x[3].is_nan());
+    /// assert!((x[4] - Real::ln(1e-40_f32)).abs() < 1e-5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ln(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::ln(self)
+    }
+
+    /// Lane-wise power, `self^n`.
+    ///
+    /// At runtime, evaluated as `exp(n * ln(|self|))`, negated for negative bases, and odd
+    /// integer exponents, so the relative error grows with the magnitude of the result. Finite
+    /// negative bases are NaN for non-integer exponents, same as [`Real::powf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(const_trait_impl)]
+    ///
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..=200 {
+    ///     for j in 0..40 {
+    ///         let a = Vec::<f32, 5>::splat(i as f32 * 0.05);
+    ///         let b = Vec::from_array([0, 1, 2, 3, 4].map(|k| (j * 5 + k) as f32 * 0.1 - 10.0));
+    ///         let pow = a.powf(b);
+    ///
+    ///         for k in 0..5 {
+    ///             let expected = Real::powf(a[k], b[k]);
+    ///
+    ///             if expected.is_normal() {
+    ///                 assert!((pow[k] - expected).abs() <= expected * 5e-6);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// const A: Vec<f32, 5> = Vec::from_array([-2.0, -2.0, -2.0, f32::NAN, 1.0]);
+    /// const B: Vec<f32, 5> = Vec::from_array([2.0, 3.0, 0.5, 0.0, f32::NAN]);
+    /// const POW: Vec<f32, 5> = A.powf(B);
+    ///
+    /// let pow = A.powf(B);
+    ///
+    /// for pow in [pow, POW] {
+    ///     assert_eq!([pow[0], pow[1], pow[3], pow[4]], [4.0, -8.0, 1.0, 1.0]);
+    ///     assert!(pow[2].is_nan());
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn powf(self, n: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::powf(self, n)
+    }
+
+    /// Lane-wise fast approximation of the reciprocal square root.
+    ///
+    /// See [`Real::fast_rsqrt`] for the accuracy, and special values.
+    #[inline]
+    #[must_use]
+    pub const fn fast_rsqrt(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::fast_rsqrt(self)
+    }
+
+    /// Lane-wise fast approximation of [`recip`](Self::recip).
+    ///
+    /// See [`Real::fast_recip`] for the accuracy, and special values.
+    #[inline]
+    #[must_use]
+    pub const fn fast_recip(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::fast_recip(self)
+    }
+
+    /// Lane-wise fast approximation of [`sin`](Self::sin).
+    ///
+    /// See [`Real::fast_sin`] for the accuracy. At runtime, chunks of [`LANES`](Self::LANES) lanes
+    /// are evaluated with the same operations as [`Real::fast_sin`], so every lane is the same as
+    /// the scalar result, in const, and non-const contexts.
+    ///
+    /// The same applies to the other `fast_*` functions. `examples/fast.rs` compares the speed with
+    /// [`sin`](Self::sin).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::{Real, Vec};
+    ///
+    /// for i in 0..40_000 {
+    ///     let x = [0, 1, 2, 3, 4].map(|j| (i * 5 + j) as f32 * 0.01 - 1000.0);
+    ///     let vec = Vec::<f32, 5>::from_array(x);
+    ///     let (fast, exact) = (vec.fast_sin(), vec.sin());
+    ///
+    ///     for j in 0..5 {
+    ///         assert!((fast[j] - exact[j]).abs() < 2e-6);
+    ///         assert_eq!(fast[j].to_bits(), Real::fast_sin(x[j]).to_bits());
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn fast_sin(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::fast_sin(self)
+    }
+
+    /// Lane-wise fast approximation of [`cos`](Self::cos).
+    ///
+    /// See [`Real::fast_cos`] for the accuracy.
+    #[inline]
+    #[must_use]
+    pub const fn fast_cos(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::fast_cos(self)
+    }
+
+    /// Lane-wise fast approximation of [`sin_cos`](Self::sin_cos).
+    ///
+    /// See [`Real::fast_sin_cos`] for the accuracy.
+    #[inline]
+    #[must_use]
+    pub const fn fast_sin_cos(self) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::fast_sin_cos(self)
+    }
+
+    /// Lane-wise fast approximation of the four quadrant arctangent of `self` (`y`), and `x`.
+    ///
+    /// See [`Real::fast_atan2`] for the accuracy.
+    #[inline]
+    #[must_use]
+    pub const fn fast_atan2(self, x: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::fast_atan2(self, x)
+    }
+
+    /// Lane-wise fast approximation of [`exp`](Self::exp).
+    ///
+    /// See [`Real::fast_exp`] for the accuracy.
+    #[inline]
+    #[must_use]
+    pub const fn fast_exp(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); <Lanes<T, N> as LaneCount>::LANES]:,
+    {
+        math::fast_exp(self)
+    }
+}
+
+impl<T, const N: usize> const Deref for Vec<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &[T; N] {
+        &self.array
+    }
+}
+
+impl<T, const N: usize> const DerefMut for Vec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T; N] {
+        &mut self.array
+    }
+}
+
+impl<T, const N: usize> const PartialEq for Vec<T, N>
+where
+    T: ~const Element,
+    T: ~const PartialEq,
+    Lanes<T, N>: LaneCount,
+    [(); <Lanes<T, N> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn eq(&self, other: &Vec<T, N>) -> bool {
+        self.lanes_eq(*other).all()
+    }
+}
+
+impl<T, const N: usize> Eq for Vec<T, N>
+where
+    T: Element,
+    T: Eq,
+    Lanes<T, N>: LaneCount,
+    [(); <Lanes<T, N> as LaneCount>::LANES]:,
+{
+}
+
+/// Lexicographic ordering, same as arrays.
+impl<T, const N: usize> const PartialOrd for Vec<T, N>
+where
+    T: ~const Element,
+    T: ~const Ord,
+    Lanes<T, N>: LaneCount,
+    [(); <Lanes<T, N> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Vec<T, N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexicographic ordering, same as arrays.
+impl<T, const N: usize> const Ord for Vec<T, N>
+where
+    T: ~const Element,
+    T: ~const Ord,
+    Lanes<T, N>: LaneCount,
+    [(); <Lanes<T, N> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn cmp(&self, other: &Vec<T, N>) -> Ordering {
+        let mut i = 0;
+
+        while i < N {
+            match self.array[i].cmp(&other.array[i]) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+
+            i += 1;
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl<T, const N: usize> Hash for Vec<T, N>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.array.hash(state);
+    }
+}
+
+impl<T, const N: usize> const Default for Vec<T, N>
+where
+    T: Copy,
+    T: ~const Zero,
+{
+    /// Creates a new vector with all elements set to zero.
+    #[inline]
+    fn default() -> Vec<T, N> {
+        Self::zero()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Vec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Vec<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T, N> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut Vec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T, N> {
+        self.iter_mut()
+    }
+}
+
+/// Collects exactly `N` lanes, any further items are not consumed.
+///
+/// # Panics
+///
+/// Panics if the iterator yields less than `N` items.
+impl<T, const N: usize> FromIterator<T> for Vec<T, N> {
+    #[inline]
+    fn from_iter<I>(iter: I) -> Vec<T, N>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut output = MaybeUninit::<[T; N]>::uninit();
+        let ptr = output.as_mut_ptr().cast::<T>();
+        let mut iter = iter.into_iter();
+        let mut i = 0;
+
+        while i < N {
+            let element = match iter.next() {
+                Some(element) => element,
+                None => panic!("iterator yielded {i} items, expected {N}"),
+            };
+
+            // SAFETY: `i` is within `0..N`
+            unsafe {
+                ptr.add(i).write(element);
+            }
+
+            i += 1;
+        }
+
+        // SAFETY: every lane was written above
+        Vec::from_array(unsafe { output.assume_init() })
+    }
+}
+
+impl<T, const N: usize> TryFrom<&[T]> for Vec<T, N>
+where
+    T: Copy,
+{
+    type Error = TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<Vec<T, N>, TryFromSliceError> {
+        <[T; N]>::try_from(slice).map(Vec::from_array)
+    }
+}
+
+macro_rules! impl_op {
+    { $trait:ident, $trait_assign:ident, $fn:ident, $fn_assign:ident, $op:tt } => {
+        impl<T, const N: usize> const $trait for Vec<T, N>
+        where
+            T: ~const Element,
+            T: ~const $trait<Output = T>,
+            Lanes<T, N>: LaneCount,
+            [(); <Lanes<T, N> as LaneCount>::LANES]:,
+        {
+            type Output = Vec<T, N>;
+
+            #[inline]
+            #[must_use]
+            fn $fn(mut self, other: Vec<T, N>) -> Vec<T, N> {
+                let mut i = 0;
+
+                while i < Self::CHUNKS {
+                    self.set_chunk(i, self.chunk(i) $op other.chunk(i));
+                    i += 1;
+                }
+
+                let mut i = Self::CHUNKS * Self::LANES;
+
+                while i < N {
+                    self.array[i] = self.array[i] $op other.array[i];
+                    i += 1;
+                }
+
+                self
+            }
+        }
+
+        impl<T, const N: usize> const $trait_assign for Vec<T, N>
+        where
+            T: ~const Element,
+            T: ~const $trait <Output = T>,
+            Lanes<T, N>: LaneCount,
+            [(); <Lanes<T, N> as LaneCount>::LANES]:,
+        {
+            #[inline]
+            #[must_use]
+            fn $fn_assign(&mut self, other: Vec<T, N>) {
+                *self = *self $op other;
+            }
+        }
+    }
+}
+
+impl_op! { Add, AddAssign, add, add_assign, + }
+impl_op! { Div, DivAssign, div, div_assign, / }
+impl_op! { Mul, MulAssign, mul, mul_assign, * }
+impl_op! { Rem, RemAssign, rem, rem_assign, % }
+impl_op! { Sub, SubAssign, sub, sub_assign, - }
+
+impl<T, const LEN: usize> fmt::Debug for Vec<T, LEN>
+where
+    T: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.array, fmt)
+    }
+}
+
+/// Formats as `(1, 2, 3)`, or with one lane per line for `{:#}`.
+///
+/// Width, and precision apply to each lane.
+///
+/// # Examples
+///
+/// ```
+/// use meth::Vec;
+///
+/// let vec = Vec::from_array([1.0, 2.5, -3.0]);
+///
+/// assert_eq!(format!("{vec:.1}"), "(1.0, 2.5, -3.0)");
+/// ```
+impl<T, const LEN: usize> fmt::Display for Vec<T, LEN>
+where
+    T: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::tuple(fmt, &self.array)
+    }
+}
+
+/// Parses lanes separated by commas, or whitespace, optionally enclosed in `(` `)`, or `[` `]`.
+///
+/// # Examples
+///
+/// ```
+/// use meth::Vec;
+///
+/// assert_eq!(*"1 2 3".parse::<Vec<i32, 3>>().unwrap(), [1, 2, 3]);
+/// assert_eq!(*"(1, 2, 3)".parse::<Vec<i32, 3>>().unwrap(), [1, 2, 3]);
+/// assert_eq!(*"[1,2,3]".parse::<Vec<i32, 3>>().unwrap(), [1, 2, 3]);
+///
+/// let error = "1, x, 3".parse::<Vec<i32, 3>>().unwrap_err();
+///
+/// assert_eq!(error.component(), 1);
+/// ```
+impl<T, const N: usize> FromStr for Vec<T, N>
+where
+    T: FromStr,
+{
+    type Err = ParseError<T::Err>;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Vec<T, N>, ParseError<T::Err>> {
+        crate::parse::parse_array(string, None).map(Vec::from_array)
     }
 }
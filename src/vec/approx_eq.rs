@@ -0,0 +1,62 @@
+use super::{Element, LaneCount, Lanes, Vec};
+use crate::{ApproxEq, Real};
+use core::ops::{Mul, Sub};
+
+/// Lane-wise approximate equality, every lane must be approximately equal.
+impl<T, const N: usize> const ApproxEq for Vec<T, N>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: ~const ApproxEq<Epsilon = T>,
+    T: ~const PartialOrd,
+    T: ~const Sub<Output = T>,
+    T: ~const Mul<Output = T>,
+    Lanes<T, N>: LaneCount,
+    [(); <Lanes<T, N> as LaneCount>::LANES]:,
+{
+    type Epsilon = T;
+
+    const DEFAULT_EPSILON: T = <T as ApproxEq>::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T = <T as ApproxEq>::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = <T as ApproxEq>::DEFAULT_MAX_ULPS;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Vec<T, N>, epsilon: T) -> bool {
+        let equal = self.lanes_eq(*other);
+        let difference = (*self - *other).abs();
+
+        (equal | difference.lanes_le(Vec::splat(epsilon))).all()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Vec<T, N>, epsilon: T, max_relative: T) -> bool {
+        let equal = self.lanes_eq(*other);
+        let difference = (*self - *other).abs();
+        let largest = self.abs().max(other.abs());
+
+        // infinities of different signs, or an infinity and a finite number
+        let finite = difference.lanes_lt(Vec::splat(<T as Real>::INFINITY));
+        let absolute = difference.lanes_le(Vec::splat(epsilon));
+        let relative = difference.lanes_le(largest * Vec::splat(max_relative));
+
+        (equal | (finite & (absolute | relative))).all()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Vec<T, N>, epsilon: T, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+
+        let mut a_iter = self.iter();
+        let mut b_iter = other.iter();
+
+        while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
+            if !a.ulps_eq(b, epsilon, max_ulps) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
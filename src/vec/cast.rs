@@ -0,0 +1,142 @@
+use super::element::Sealed;
+use super::{Element, LaneCount, Lanes, Vec};
+use crate::intrinsics;
+use crate::intrinsics::const_eval_select;
+use core::mem::{self, MaybeUninit};
+
+/// Lane-wise numeric conversion, with the same semantics as `as`.
+///
+/// Float to integer conversions saturate, and NaN converts to `0`.
+pub trait Cast<U>: Sealed
+where
+    U: Element,
+{
+    /// Converts `self` to `U`, same as `self as U`.
+    fn cast(self) -> U;
+}
+
+macro_rules! impl_cast {
+    { $from:ty => $($to:ty),* } => {
+        $(
+            impl const Cast<$to> for $from {
+                #[inline]
+                fn cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_cast_all {
+    { $($from:ty),* } => {
+        $(
+            impl_cast! { $from => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 }
+        )*
+    }
+}
+
+impl_cast_all! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 }
+
+#[inline]
+#[must_use]
+pub const fn cast<T, U, const N: usize>(vec: Vec<T, N>) -> Vec<U, N>
+where
+    T: ~const Element,
+    T: ~const Cast<U>,
+    U: ~const Element,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_cast<T, U, const N: usize>(vec: Vec<T, N>) -> Vec<U, N>
+    where
+        T: ~const Element,
+        T: ~const Cast<U>,
+        U: ~const Element,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut output = MaybeUninit::<[U; N]>::uninit();
+        let ptr = output.as_mut_ptr().cast::<U>();
+        let mut i = 0;
+
+        while i < N {
+            // SAFETY: `i` is within `0..N`
+            unsafe {
+                ptr.add(i).write(Cast::<U>::cast(vec.array[i]));
+            }
+
+            i += 1;
+        }
+
+        // SAFETY: every lane was written above
+        Vec::from_array(unsafe { output.assume_init() })
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_cast<T, U, const N: usize>(vec: Vec<T, N>) -> Vec<U, N>
+    where
+        T: Element,
+        T: Cast<U>,
+        U: Element,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        unsafe {
+            let mut output = MaybeUninit::<[U; N]>::uninit();
+            let ptr = output.as_mut_ptr().cast::<U>();
+            let mut iter = vec.array.chunks_exact(<Lanes<T, N> as LaneCount>::LANES);
+            let mut i = 0;
+
+            while let Some(element) = iter.next() {
+                let element = element.as_ptr() as *const [T; <Lanes<T, N> as LaneCount>::LANES];
+                let output = ptr.add(i).cast::<[U; <Lanes<T, N> as LaneCount>::LANES]>();
+
+                output.write(intrinsics::simd_as(*element));
+                i += <Lanes<T, N> as LaneCount>::LANES;
+            }
+
+            let mut iter = iter.remainder().iter();
+
+            while let Some(element) = iter.next() {
+                ptr.add(i).write(Cast::<U>::cast(*element));
+                i += 1;
+            }
+
+            Vec::from_array(output.assume_init())
+        }
+    }
+
+    unsafe { const_eval_select!((vec,), scalar_cast, simd_cast) }
+}
+
+macro_rules! impl_bits {
+    { $float:ty, $bits:ty } => {
+        impl<const N: usize> Vec<$float, N> {
+            /// Raw transmutation of each lane to its bits.
+            #[inline]
+            #[must_use]
+            pub const fn to_bits(self) -> Vec<$bits, N> {
+                // SAFETY: both types have the same size, and every bit pattern is valid
+                unsafe { mem::transmute_copy(&self) }
+            }
+
+            /// Raw transmutation of each lane from its bits.
+            #[inline]
+            #[must_use]
+            pub const fn from_bits(bits: Vec<$bits, N>) -> Vec<$float, N> {
+                // SAFETY: both types have the same size, and every bit pattern is valid
+                unsafe { mem::transmute_copy(&bits) }
+            }
+        }
+    }
+}
+
+impl_bits! { f32, u32 }
+impl_bits! { f64, u64 }
@@ -0,0 +1,49 @@
+use super::{Element, LaneCount, Lanes, Mask, Vec};
+use core::cmp::{PartialEq, PartialOrd};
+
+macro_rules! impl_cmp {
+    { $fn:ident, $fn_lanes:ident, $trait:ident, $op:tt } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const N: usize>(a: Vec<T, N>, b: Vec<T, N>) -> Mask<N>
+        where
+            T: ~const Element,
+            T: ~const $trait,
+            Lanes<T, N>: LaneCount,
+            [(); Lanes::<T, N>::LANES]:,
+        {
+            let mut mask = [false; N];
+            let mut i = 0;
+
+            while i < Vec::<T, N>::CHUNKS {
+                let chunk = a.chunk(i).$fn_lanes(b.chunk(i)).to_array();
+
+                // SAFETY: chunks within `0..CHUNKS` are within the mask
+                unsafe {
+                    (&mut mask as *mut [bool; N])
+                        .cast::<[bool; <Lanes<T, N> as LaneCount>::LANES]>()
+                        .add(i)
+                        .write(chunk);
+                }
+
+                i += 1;
+            }
+
+            let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+            while i < N {
+                mask[i] = a.array[i] $op b.array[i];
+                i += 1;
+            }
+
+            Mask::from_array(mask)
+        }
+    }
+}
+
+impl_cmp! { eq, lanes_eq, PartialEq, == }
+impl_cmp! { ne, lanes_ne, PartialEq, != }
+impl_cmp! { lt, lanes_lt, PartialOrd, < }
+impl_cmp! { le, lanes_le, PartialOrd, <= }
+impl_cmp! { gt, lanes_gt, PartialOrd, > }
+impl_cmp! { ge, lanes_ge, PartialOrd, >= }
@@ -0,0 +1,197 @@
+//! Runtime CPU feature dispatch, enabled with the `runtime-dispatch` feature.
+//!
+//! Heavy kernels are compiled once per instruction set, with chunks as wide as its registers, and
+//! the widest instruction set the CPU supports is detected once, on first use.
+
+use super::element::Sealed;
+use super::Element;
+use crate::identity::{One, Zero};
+use crate::Simd;
+use core::ops::{Add, Mul};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Vectors smaller than this, in bytes, use the compile-time kernels, as they do not amortize the
+/// dispatch.
+pub(crate) const MIN_SIZE: usize = 256;
+
+/// Instruction sets, narrowest first.
+#[derive(Clone, Copy)]
+#[repr(u8)]
+pub(crate) enum Level {
+    Baseline,
+    Avx2,
+    Avx512,
+}
+
+/// Detected level, `u8::MAX` before the first use.
+static LEVEL: AtomicU8 = AtomicU8::new(u8::MAX);
+
+#[inline]
+pub(crate) fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Baseline,
+        1 => Level::Avx2,
+        2 => Level::Avx512,
+        _ => {
+            // racing threads detect, and store the same level
+            let level = detect();
+
+            LEVEL.store(level as u8, Ordering::Relaxed);
+
+            level
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cold]
+fn detect() -> Level {
+    if std::is_x86_feature_detected!("avx512f") {
+        Level::Avx512
+    } else if std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma") {
+        Level::Avx2
+    } else {
+        Level::Baseline
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[cold]
+fn detect() -> Level {
+    Level::Baseline
+}
+
+#[inline(always)]
+pub(crate) unsafe fn sum_unordered<T, const LANES: usize>(slice: &[T]) -> T
+where
+    T: Element,
+    T: Zero,
+    T: Add<Output = T>,
+{
+    let mut accumulator = Simd::<T, LANES>::splat(<T as Zero>::zero());
+    let mut iter = slice.chunks_exact(LANES);
+
+    while let Some(element) = iter.next() {
+        let element = element.as_ptr().cast::<[T; LANES]>();
+
+        accumulator += Simd::from_array(*element);
+    }
+
+    let mut output = accumulator.sum_unordered();
+    let mut iter = iter.remainder().iter();
+
+    while let Some(element) = iter.next() {
+        output = output + *element;
+    }
+
+    output
+}
+
+#[inline(always)]
+pub(crate) unsafe fn product_unordered<T, const LANES: usize>(slice: &[T]) -> T
+where
+    T: Element,
+    T: One,
+    T: Mul<Output = T>,
+{
+    let mut accumulator = Simd::<T, LANES>::splat(<T as One>::one());
+    let mut iter = slice.chunks_exact(LANES);
+
+    while let Some(element) = iter.next() {
+        let element = element.as_ptr().cast::<[T; LANES]>();
+
+        accumulator *= Simd::from_array(*element);
+    }
+
+    let mut output = accumulator.product_unordered();
+    let mut iter = iter.remainder().iter();
+
+    while let Some(element) = iter.next() {
+        output = output * *element;
+    }
+
+    output
+}
+
+#[inline(always)]
+pub(crate) unsafe fn dot<T, const LANES: usize>(a: &[T], b: &[T]) -> T
+where
+    T: Element,
+    T: Zero,
+    T: Add<Output = T>,
+{
+    let mut accumulator = Simd::<T, LANES>::splat(<T as Zero>::zero());
+    let mut a_iter = a.chunks_exact(LANES);
+    let mut b_iter = b.chunks_exact(LANES);
+
+    while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
+        let a = a.as_ptr().cast::<[T; LANES]>();
+        let b = b.as_ptr().cast::<[T; LANES]>();
+
+        accumulator = Simd::from_array(*a).mul_add(Simd::from_array(*b), accumulator);
+    }
+
+    let mut output = accumulator.sum();
+    let mut a_iter = a_iter.remainder().iter();
+    let mut b_iter = b_iter.remainder().iter();
+
+    while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
+        output = <T as Sealed>::lane_mul_add(*a, *b, output);
+    }
+
+    output
+}
+
+/// Calls `kernel` compiled for the detected level, with chunks as wide as its registers.
+macro_rules! dispatch {
+    { $kernel:ident::<$ty:ty>($($arg:ident: $arg_ty:ty),*) -> $output:ty } => {{
+        use $crate::vec::dispatch;
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx512f")]
+        unsafe fn avx512($($arg: $arg_ty),*) -> $output {
+            dispatch::$kernel::<$ty, { 64 / ::core::mem::size_of::<$ty>() }>($($arg),*)
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx2,fma")]
+        unsafe fn avx2($($arg: $arg_ty),*) -> $output {
+            dispatch::$kernel::<$ty, { 32 / ::core::mem::size_of::<$ty>() }>($($arg),*)
+        }
+
+        fn baseline($($arg: $arg_ty),*) -> $output {
+            unsafe { dispatch::$kernel::<$ty, { 16 / ::core::mem::size_of::<$ty>() }>($($arg),*) }
+        }
+
+        // SAFETY: the level is only above baseline if the CPU supports it
+        match dispatch::level() {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            dispatch::Level::Avx512 => unsafe { avx512($($arg),*) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            dispatch::Level::Avx2 => unsafe { avx2($($arg),*) },
+            _ => baseline($($arg),*),
+        }
+    }};
+}
+
+/// Implements the dispatched methods of `Kernel` for `$ty`.
+macro_rules! impl_dispatch {
+    { $ty:ty } => {
+        #[inline]
+        fn dispatch_sum_unordered(slice: &[$ty]) -> $ty {
+            $crate::vec::dispatch::dispatch! { sum_unordered::<$ty>(slice: &[$ty]) -> $ty }
+        }
+
+        #[inline]
+        fn dispatch_product_unordered(slice: &[$ty]) -> $ty {
+            $crate::vec::dispatch::dispatch! { product_unordered::<$ty>(slice: &[$ty]) -> $ty }
+        }
+
+        #[inline]
+        fn dispatch_dot(a: &[$ty], b: &[$ty]) -> $ty {
+            $crate::vec::dispatch::dispatch! { dot::<$ty>(a: &[$ty], b: &[$ty]) -> $ty }
+        }
+    }
+}
+
+pub(crate) use {dispatch, impl_dispatch};
@@ -1,30 +1,281 @@
+use crate::intrinsics;
+use crate::Real;
+use core::ops::BitAnd;
+
 mod sealed {
-    pub trait Sealed: Copy + Sized {}
+    /// Scalar lane operations, called in const contexts, and for remainders.
+    pub trait Sealed: Copy + Sized {
+        fn lane_min(self, other: Self) -> Self;
+        fn lane_max(self, other: Self) -> Self;
+        fn lane_abs(self) -> Self;
+        fn lane_mul_add(self, a: Self, b: Self) -> Self;
+    }
+
+    /// SIMD lane operations, called in non-const contexts.
+    pub trait Kernel: Copy + Sized {
+        unsafe fn simd_min<const N: usize>(a: [Self; N], b: [Self; N]) -> [Self; N];
+        unsafe fn simd_max<const N: usize>(a: [Self; N], b: [Self; N]) -> [Self; N];
+        unsafe fn simd_abs<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_mul_add<const N: usize>(
+            a: [Self; N],
+            b: [Self; N],
+            c: [Self; N],
+        ) -> [Self; N];
+
+        /// Lane-wise division. Panics for integers, same as `/`.
+        unsafe fn simd_div<const N: usize>(a: [Self; N], b: [Self; N]) -> [Self; N];
+
+        /// Lane-wise remainder. Panics for integers, same as `%`.
+        unsafe fn simd_rem<const N: usize>(a: [Self; N], b: [Self; N]) -> [Self; N];
+
+        /// Sum in an unspecified order, with the widest instruction set the CPU supports.
+        #[cfg(feature = "runtime-dispatch")]
+        fn dispatch_sum_unordered(slice: &[Self]) -> Self;
+
+        /// Product in an unspecified order, with the widest instruction set the CPU supports.
+        #[cfg(feature = "runtime-dispatch")]
+        fn dispatch_product_unordered(slice: &[Self]) -> Self;
+
+        /// Dot product in an unspecified order, with the widest instruction set the CPU supports.
+        #[cfg(feature = "runtime-dispatch")]
+        fn dispatch_dot(a: &[Self], b: &[Self]) -> Self;
+    }
 }
 
-use sealed::Sealed;
+pub(crate) use sealed::{Kernel, Sealed};
 
 /// SIMD-able types.
-pub trait Element: Sealed {}
+pub trait Element: Sealed + Kernel {
+    /// Signed integer of the same width, used for comparison masks.
+    type Mask: Copy + BitAnd;
+}
 
 macro_rules! impl_element {
+    { $ty:ty, $mask:ty } => {
+        impl const Element for $ty {
+            type Mask = $mask;
+        }
+    }
+}
+
+/// Tests if any lane divides `min` by `minus_one`, which overflows for signed integers.
+#[inline]
+unsafe fn overflows<T, const N: usize>(a: [T; N], b: [T; N], min: T, minus_one: T) -> bool
+where
+    T: Element,
+    T: PartialEq,
+{
+    let min = intrinsics::simd_eq(a, [min; N]);
+    let minus_one = intrinsics::simd_eq(b, [minus_one; N]);
+    let mut i = 0;
+
+    while i < N {
+        if min[i] && minus_one[i] {
+            return true;
+        }
+
+        i += 1;
+    }
+
+    false
+}
+
+macro_rules! impl_int {
     { $ty:ty } => {
-        impl const sealed::Sealed for $ty {}
-        impl const Element for $ty {}
+        impl sealed::Kernel for $ty {
+            #[inline]
+            unsafe fn simd_min<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_select(intrinsics::simd_lt(a, b), a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_max<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_select(intrinsics::simd_gt(a, b), a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_abs<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                let zero = [0; N];
+
+                // wraps on overflow, same as `wrapping_abs`
+                intrinsics::simd_select(intrinsics::simd_lt(a, zero), intrinsics::simd_sub(zero, a), a)
+            }
+
+            #[inline]
+            unsafe fn simd_mul_add<const N: usize>(a: [$ty; N], b: [$ty; N], c: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_add(intrinsics::simd_mul(a, b), c)
+            }
+
+            #[inline]
+            unsafe fn simd_div<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                // the intrinsic is undefined behaviour for a zero divisor, and `MIN / -1`
+                if intrinsics::simd_eq(b, [0; N]).contains(&true) {
+                    panic!("attempt to divide by zero");
+                }
+
+                // unsigned integers can't overflow, and `!0` is their maximum
+                if <$ty>::MIN != 0 && overflows(a, b, <$ty>::MIN, !0) {
+                    panic!("attempt to divide with overflow");
+                }
+
+                intrinsics::simd_div(a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_rem<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                // the intrinsic is undefined behaviour for a zero divisor, and `MIN % -1`
+                if intrinsics::simd_eq(b, [0; N]).contains(&true) {
+                    panic!("attempt to calculate the remainder with a divisor of zero");
+                }
+
+                // unsigned integers can't overflow, and `!0` is their maximum
+                if <$ty>::MIN != 0 && overflows(a, b, <$ty>::MIN, !0) {
+                    panic!("attempt to calculate the remainder with overflow");
+                }
+
+                intrinsics::simd_rem(a, b)
+            }
+
+            #[cfg(feature = "runtime-dispatch")]
+            super::dispatch::impl_dispatch! { $ty }
+        }
+    }
+}
+
+macro_rules! impl_signed {
+    { $ty:ty, $mask:ty } => {
+        impl const sealed::Sealed for $ty {
+            #[inline]
+            fn lane_min(self, other: $ty) -> $ty {
+                if self < other { self } else { other }
+            }
+
+            #[inline]
+            fn lane_max(self, other: $ty) -> $ty {
+                if self > other { self } else { other }
+            }
+
+            #[inline]
+            fn lane_abs(self) -> $ty {
+                self.wrapping_abs()
+            }
+
+            #[inline]
+            fn lane_mul_add(self, a: $ty, b: $ty) -> $ty {
+                self * a + b
+            }
+        }
+
+        impl_int! { $ty }
+        impl_element! { $ty, $mask }
+    }
+}
+
+macro_rules! impl_unsigned {
+    { $ty:ty, $mask:ty } => {
+        impl const sealed::Sealed for $ty {
+            #[inline]
+            fn lane_min(self, other: $ty) -> $ty {
+                if self < other { self } else { other }
+            }
+
+            #[inline]
+            fn lane_max(self, other: $ty) -> $ty {
+                if self > other { self } else { other }
+            }
+
+            #[inline]
+            fn lane_abs(self) -> $ty {
+                self
+            }
+
+            #[inline]
+            fn lane_mul_add(self, a: $ty, b: $ty) -> $ty {
+                self * a + b
+            }
+        }
+
+        impl_int! { $ty }
+        impl_element! { $ty, $mask }
+    }
+}
+
+macro_rules! impl_float {
+    { $ty:ty, $mask:ty } => {
+        impl const sealed::Sealed for $ty {
+            #[inline]
+            fn lane_min(self, other: $ty) -> $ty {
+                <$ty as Real>::min(self, other)
+            }
+
+            #[inline]
+            fn lane_max(self, other: $ty) -> $ty {
+                <$ty as Real>::max(self, other)
+            }
+
+            #[inline]
+            fn lane_abs(self) -> $ty {
+                <$ty as Real>::abs(self)
+            }
+
+            #[inline]
+            fn lane_mul_add(self, a: $ty, b: $ty) -> $ty {
+                <$ty as Real>::mul_add(self, a, b)
+            }
+        }
+
+        impl sealed::Kernel for $ty {
+            #[inline]
+            unsafe fn simd_min<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_fmin(a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_max<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_fmax(a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_abs<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_fabs(a)
+            }
+
+            #[inline]
+            unsafe fn simd_mul_add<const N: usize>(a: [$ty; N], b: [$ty; N], c: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_fma(a, b, c)
+            }
+
+            #[inline]
+            unsafe fn simd_div<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_div(a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_rem<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                intrinsics::simd_rem(a, b)
+            }
+
+            #[cfg(feature = "runtime-dispatch")]
+            super::dispatch::impl_dispatch! { $ty }
+        }
+
+        impl_element! { $ty, $mask }
     }
 }
 
-impl_element! { i8 }
-impl_element! { i16 }
-impl_element! { i32 }
-impl_element! { i64 }
-impl_element! { isize }
+impl_signed! { i8, i8 }
+impl_signed! { i16, i16 }
+impl_signed! { i32, i32 }
+impl_signed! { i64, i64 }
+impl_signed! { i128, i128 }
+impl_signed! { isize, isize }
 
-impl_element! { u8 }
-impl_element! { u16 }
-impl_element! { u32 }
-impl_element! { u64 }
-impl_element! { usize }
+impl_unsigned! { u8, i8 }
+impl_unsigned! { u16, i16 }
+impl_unsigned! { u32, i32 }
+impl_unsigned! { u64, i64 }
+impl_unsigned! { u128, i128 }
+impl_unsigned! { usize, isize }
 
-impl_element! { f32 }
-impl_element! { f64 }
+impl_float! { f32, i32 }
+impl_float! { f64, i64 }
@@ -0,0 +1,656 @@
+use super::Element;
+use crate::Real;
+
+mod sealed {
+    use super::{Element, Real};
+
+    /// SIMD float operations, called in non-const contexts.
+    ///
+    /// Transcendental functions are evaluated with polynomial approximations on whole chunks of
+    /// lanes, instead of calling `libm` for each lane.
+    pub trait Float: Element + Real {
+        unsafe fn simd_sqrt<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_rsqrt<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_recip<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_floor<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_ceil<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_round<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_sin<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_cos<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_sin_cos<const N: usize>(a: [Self; N]) -> ([Self; N], [Self; N]);
+        unsafe fn simd_tan<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_exp<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_ln<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_powf<const N: usize>(a: [Self; N], b: [Self; N]) -> [Self; N];
+        unsafe fn simd_fast_rsqrt<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_fast_recip<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_fast_sin<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_fast_cos<const N: usize>(a: [Self; N]) -> [Self; N];
+        unsafe fn simd_fast_sin_cos<const N: usize>(a: [Self; N]) -> ([Self; N], [Self; N]);
+        unsafe fn simd_fast_atan2<const N: usize>(a: [Self; N], b: [Self; N]) -> [Self; N];
+        unsafe fn simd_fast_exp<const N: usize>(a: [Self; N]) -> [Self; N];
+    }
+}
+
+pub(crate) use sealed::Float;
+
+macro_rules! impl_float {
+    {
+        $module:ident: $ty:ident, $bits:ty, $int:ty,
+        mantissa: $mantissa:literal,
+        bias: $bias:literal,
+        subnormal_scale: ($scale:literal, $scale_exp:literal),
+        frac_pi_2: [$pio2_1:literal, $pio2_2:literal, $pio2_3:literal],
+        ln_2: [$ln2_hi:literal, $ln2_lo:literal],
+        exp_range: ($exp_min:literal, $exp_max:literal),
+        sin: [$($sin:literal),*],
+        cos: [$($cos:literal),*],
+        exp: [$($exp:literal),*],
+        ln: [$($ln:literal),*],
+        fast_rsqrt: $fast_rsqrt:literal,
+        fast_recip: $fast_recip:literal,
+    } => {
+        mod $module {
+            use crate::intrinsics::{
+                simd_add, simd_and, simd_as, simd_cast, simd_ceil, simd_div, simd_eq, simd_fabs,
+                simd_floor, simd_fsqrt, simd_ge, simd_gt, simd_lt, simd_mul, simd_ne, simd_or,
+                simd_rem, simd_round, simd_select, simd_shl, simd_shr, simd_sub,
+            };
+            use crate::Real;
+            use core::mem;
+
+            #[allow(clippy::excessive_precision)]
+            const SIN: &[$ty] = &[$($sin),*];
+
+            #[allow(clippy::excessive_precision)]
+            const COS: &[$ty] = &[$($cos),*];
+
+            #[allow(clippy::excessive_precision)]
+            const EXP: &[$ty] = &[$($exp),*];
+
+            #[allow(clippy::excessive_precision)]
+            const LN: &[$ty] = &[$($ln),*];
+
+            const MANTISSA_MASK: $bits = (1 << $mantissa) - 1;
+            const ONE_BITS: $bits = ($bias as $bits) << $mantissa;
+            const SIGN_BITS: $bits = 1 << (<$bits>::BITS - 1);
+
+            #[inline]
+            unsafe fn to_bits<const N: usize>(a: [$ty; N]) -> [$bits; N] {
+                mem::transmute_copy(&a)
+            }
+
+            #[inline]
+            unsafe fn from_bits<const N: usize>(a: [$bits; N]) -> [$ty; N] {
+                mem::transmute_copy(&a)
+            }
+
+            /// `2^k`, `k` must be within the normal exponent range.
+            #[inline]
+            unsafe fn exp2i<const N: usize>(k: [$int; N]) -> [$ty; N] {
+                let exponent: [$bits; N] = simd_cast(simd_add(k, [$bias; N]));
+
+                from_bits(simd_shl(exponent, [$mantissa; N]))
+            }
+
+            /// Evaluates the polynomial with `coefficients` in ascending order, at `x`.
+            #[inline]
+            unsafe fn horner<const N: usize>(x: [$ty; N], coefficients: &[$ty]) -> [$ty; N] {
+                let mut output = [0.0; N];
+
+                for &coefficient in coefficients.iter().rev() {
+                    output = simd_add(simd_mul(output, x), [coefficient; N]);
+                }
+
+                output
+            }
+
+            #[inline]
+            pub unsafe fn sqrt<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                simd_fsqrt(a)
+            }
+
+            #[inline]
+            pub unsafe fn rsqrt<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                simd_div([1.0; N], simd_fsqrt(a))
+            }
+
+            #[inline]
+            pub unsafe fn recip<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                simd_div([1.0; N], a)
+            }
+
+            #[inline]
+            pub unsafe fn floor<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                simd_floor(a)
+            }
+
+            #[inline]
+            pub unsafe fn ceil<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                simd_ceil(a)
+            }
+
+            #[inline]
+            pub unsafe fn round<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                simd_round(a)
+            }
+
+            /// Reduces `a` to `[-π/4, π/4]`, and evaluates sine, and cosine of the reduced angle,
+            /// then swaps, and negates them according to the quadrant.
+            #[inline]
+            pub unsafe fn sin_cos<const N: usize>(a: [$ty; N]) -> ([$ty; N], [$ty; N]) {
+                let k = simd_round(simd_mul(a, [<$ty as Real>::FRAC_2_PI; N]));
+
+                // Cody-Waite reduction, π/2 is split in three parts, so `k * part` is exact
+                let r = simd_sub(a, simd_mul(k, [$pio2_1; N]));
+                let r = simd_sub(r, simd_mul(k, [$pio2_2; N]));
+                let r = simd_sub(r, simd_mul(k, [$pio2_3; N]));
+                let r2 = simd_mul(r, r);
+
+                // sin(r) = r + r³ · P(r²)
+                let sin = simd_add(r, simd_mul(simd_mul(r, r2), horner(r2, SIN)));
+
+                // cos(r) = 1 - r²/2 + r⁴ · Q(r²)
+                let cos = simd_sub([1.0; N], simd_mul(r2, [0.5; N]));
+                let cos = simd_add(cos, simd_mul(simd_mul(r2, r2), horner(r2, COS)));
+
+                let quadrant: [$int; N] = simd_as(k);
+                let odd = simd_eq(simd_and(quadrant, [1; N]), [1; N]);
+                let sin_negative = simd_eq(simd_and(quadrant, [2; N]), [2; N]);
+                let cos_negative = simd_eq(simd_and(simd_add(quadrant, [1; N]), [2; N]), [2; N]);
+
+                let (sin, cos) = (simd_select(odd, cos, sin), simd_select(odd, sin, cos));
+                let sin = simd_select(sin_negative, simd_mul(sin, [-1.0; N]), sin);
+                let cos = simd_select(cos_negative, simd_mul(cos, [-1.0; N]), cos);
+
+                (sin, cos)
+            }
+
+            #[inline]
+            pub unsafe fn tan<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                let (sin, cos) = sin_cos(a);
+
+                simd_div(sin, cos)
+            }
+
+            /// `e^a = 2^k · e^r`, where `r` is within `[-ln(2)/2, ln(2)/2]`.
+            ///
+            /// Results that would be subnormal are flushed to zero.
+            #[inline]
+            pub unsafe fn exp<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                let k = simd_round(simd_mul(a, [<$ty as Real>::LOG2_E; N]));
+                let r = simd_sub(a, simd_mul(k, [$ln2_hi; N]));
+                let r = simd_sub(r, simd_mul(k, [$ln2_lo; N]));
+
+                // e^r = 1 + r + r² · P(r)
+                let p = simd_add([1.0; N], simd_add(r, simd_mul(simd_mul(r, r), horner(r, EXP))));
+
+                // `2^k` in two halves, as `k` may exceed the exponent range by one
+                let k: [$int; N] = simd_as(k);
+                let k1 = simd_shr(k, [1; N]);
+                let k2 = simd_sub(k, k1);
+                let output = simd_mul(simd_mul(p, exp2i(k1)), exp2i(k2));
+
+                let output = simd_select(
+                    simd_gt(a, [$exp_max; N]),
+                    [<$ty as Real>::INFINITY; N],
+                    output,
+                );
+
+                simd_select(simd_lt(a, [$exp_min; N]), [0.0; N], output)
+            }
+
+            /// `ln(a) = k · ln(2) + ln(m)`, where `m` is within `[√2/2, √2]`, and
+            /// `ln(m) = 2 · atanh(s)`, with `s = (m - 1) / (m + 1)`.
+            #[inline]
+            pub unsafe fn ln<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                // scale subnormals into the normal range
+                let subnormal = simd_lt(a, [<$ty>::MIN_POSITIVE; N]);
+                let x = simd_select(subnormal, simd_mul(a, [$scale; N]), a);
+                let bits = to_bits(x);
+
+                let k: [$int; N] = simd_cast(simd_shr(bits, [$mantissa; N]));
+                let k = simd_sub(k, [$bias; N]);
+                let k = simd_select(subnormal, simd_sub(k, [$scale_exp; N]), k);
+
+                // mantissa within `[1, 2)`, then `[√2/2, √2]`
+                let m = from_bits(simd_or(simd_and(bits, [MANTISSA_MASK; N]), [ONE_BITS; N]));
+                let large = simd_gt(m, [<$ty as Real>::SQRT_2; N]);
+                let m = simd_select(large, simd_mul(m, [0.5; N]), m);
+                let k = simd_select(large, simd_add(k, [1; N]), k);
+
+                let f = simd_sub(m, [1.0; N]);
+                let s = simd_div(f, simd_add([2.0; N], f));
+                let s2 = simd_mul(s, s);
+
+                // 2 · atanh(s) = 2s + 2s · s² · P(s²)
+                let s = simd_add(s, s);
+                let ln_m = simd_add(s, simd_mul(simd_mul(s, s2), horner(s2, LN)));
+
+                let k: [$ty; N] = simd_as(k);
+                let output = simd_add(
+                    simd_mul(k, [$ln2_hi; N]),
+                    simd_add(simd_mul(k, [$ln2_lo; N]), ln_m),
+                );
+
+                let output = simd_select(
+                    simd_eq(a, [<$ty as Real>::INFINITY; N]),
+                    [<$ty as Real>::INFINITY; N],
+                    output,
+                );
+
+                let output = simd_select(
+                    simd_eq(a, [0.0; N]),
+                    [<$ty as Real>::NEG_INFINITY; N],
+                    output,
+                );
+
+                let output = simd_select(simd_lt(a, [0.0; N]), [<$ty as Real>::NAN; N], output);
+
+                // NaN remains NaN
+                simd_select(simd_ne(a, a), a, output)
+            }
+
+            /// `a^b = e^(b · ln(|a|))`, negated for a negative base, and an odd exponent.
+            ///
+            /// Finite negative bases are NaN, unless the exponent is an integer.
+            #[inline]
+            pub unsafe fn powf<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                let abs = simd_fabs(a);
+                let output = exp(simd_mul(b, ln(abs)));
+
+                // infinite exponents are integers, and even, `b % 2` is NaN for them
+                let integer = simd_eq(simd_floor(b), b);
+                let odd = simd_eq(simd_fabs(simd_rem(b, [2.0; N])), [1.0; N]);
+
+                // the sign bit, so `-0` raised to an odd exponent is negative too
+                let negative = simd_ne(simd_and(to_bits(a), [SIGN_BITS; N]), [0; N]);
+                let negated = simd_select(odd, simd_mul(output, [-1.0; N]), output);
+                let output = simd_select(negative, negated, output);
+
+                // finite negative bases are NaN for other exponents, `-∞` keeps the result of `+∞`
+                let nan = simd_select(
+                    simd_eq(abs, [<$ty as Real>::INFINITY; N]),
+                    output,
+                    [<$ty as Real>::NAN; N],
+                );
+
+                let output = simd_select(
+                    simd_lt(a, [0.0; N]),
+                    simd_select(integer, output, nan),
+                    output,
+                );
+
+                // `(-1)^±∞` is one
+                let output = simd_select(
+                    simd_eq(simd_fabs(b), [<$ty as Real>::INFINITY; N]),
+                    simd_select(simd_eq(abs, [1.0; N]), [1.0; N], output),
+                    output,
+                );
+
+                // `x^0` and `1^y` are one, even for NaN
+                let output = simd_select(simd_eq(b, [0.0; N]), [1.0; N], output);
+
+                simd_select(simd_eq(a, [1.0; N]), [1.0; N], output)
+            }
+
+            /// Same as `Real::fast_rsqrt`, with the same operations in the same order, so every
+            /// lane is the same as the scalar result.
+            #[inline]
+            pub unsafe fn fast_rsqrt<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                // wraps for negative numbers, which are replaced below
+                let y = from_bits(simd_sub([$fast_rsqrt; N], simd_shr(to_bits(a), [1; N])));
+                let half = simd_mul([0.5; N], a);
+                let y = simd_mul(y, simd_sub([1.5; N], simd_mul(simd_mul(half, y), y)));
+                let y = simd_mul(y, simd_sub([1.5; N], simd_mul(simd_mul(half, y), y)));
+
+                let infinity = simd_or(
+                    to_bits([<$ty as Real>::INFINITY; N]),
+                    simd_and(to_bits(a), [SIGN_BITS; N]),
+                );
+
+                let y = simd_select(simd_eq(a, [<$ty as Real>::INFINITY; N]), [0.0; N], y);
+                let y = simd_select(simd_lt(a, [<$ty>::MIN_POSITIVE; N]), from_bits(infinity), y);
+
+                simd_select(simd_lt(a, [0.0; N]), [<$ty as Real>::NAN; N], y)
+            }
+
+            /// Same as `Real::fast_recip`, with the same operations in the same order.
+            #[inline]
+            pub unsafe fn fast_recip<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                let sign = simd_and(to_bits(a), [SIGN_BITS; N]);
+                let abs = from_bits(simd_and(to_bits(a), [!SIGN_BITS; N]));
+                let scale = simd_select(
+                    simd_gt(abs, [0.5 / <$ty>::MIN_POSITIVE; N]),
+                    [0.5; N],
+                    [1.0; N],
+                );
+
+                let x = simd_mul(abs, scale);
+
+                // wraps for infinity, and NaN, which are replaced below
+                let y = from_bits(simd_sub([$fast_recip; N], to_bits(x)));
+                let y = simd_mul(y, simd_sub([2.0; N], simd_mul(x, y)));
+                let y = simd_mul(simd_mul(y, simd_sub([2.0; N], simd_mul(x, y))), scale);
+
+                let y = simd_select(simd_gt(abs, [1.0 / <$ty>::MIN_POSITIVE; N]), [0.0; N], y);
+                let y = simd_select(
+                    simd_lt(abs, [<$ty>::MIN_POSITIVE; N]),
+                    [<$ty as Real>::INFINITY; N],
+                    y,
+                );
+
+                from_bits(simd_or(to_bits(y), sign))
+            }
+
+            /// Same as `Real::fast_sin_cos`, with the same operations in the same order.
+            #[inline]
+            #[allow(clippy::excessive_precision)]
+            pub unsafe fn fast_sin_cos<const N: usize>(a: [$ty; N]) -> ([$ty; N], [$ty; N]) {
+                // rounds half away from zero, saturating
+                let t = simd_mul(a, [<$ty as Real>::FRAC_2_PI; N]);
+                let t = simd_select(
+                    simd_lt(t, [0.0; N]),
+                    simd_sub(t, [0.5; N]),
+                    simd_add(t, [0.5; N]),
+                );
+
+                let quadrant: [$int; N] = simd_as(t);
+                let k: [$ty; N] = simd_as(quadrant);
+
+                // π/2 in two parts, the first part is exact when multiplied by `k`
+                let r = simd_sub(a, simd_mul(k, [1.5703125; N]));
+                let r = simd_sub(r, simd_mul(k, [4.838267948966e-4; N]));
+                let r2 = simd_mul(r, r);
+
+                let sin = simd_mul(r2, [0.008151506332465959; N]);
+                let sin = simd_add([-0.16662472194586495; N], sin);
+                let sin = simd_mul(r, simd_add([0.9999985632639604; N], simd_mul(r2, sin)));
+
+                let cos = simd_mul(r2, [-0.0013585779264842408; N]);
+                let cos = simd_add([0.04165501492488376; N], cos);
+                let cos = simd_add([-0.4999985641918218; N], simd_mul(r2, cos));
+                let cos = simd_add([0.9999999723284944; N], simd_mul(r2, cos));
+
+                let odd = simd_ne(simd_and(quadrant, [1; N]), [0; N]);
+                let sin_negative = simd_ne(simd_and(quadrant, [2; N]), [0; N]);
+                let cos_negative = simd_ne(simd_and(simd_add(quadrant, [1; N]), [2; N]), [0; N]);
+
+                let (sin, cos) = (simd_select(odd, cos, sin), simd_select(odd, sin, cos));
+                let sin = simd_select(sin_negative, simd_mul(sin, [-1.0; N]), sin);
+                let cos = simd_select(cos_negative, simd_mul(cos, [-1.0; N]), cos);
+
+                (sin, cos)
+            }
+
+            /// Same as `Real::fast_atan2`, of `a` (`y`), and `b` (`x`), with the same operations
+            /// in the same order.
+            #[inline]
+            #[allow(clippy::excessive_precision)]
+            pub unsafe fn fast_atan2<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                let abs_x = from_bits(simd_and(to_bits(b), [!SIGN_BITS; N]));
+                let abs_y = from_bits(simd_and(to_bits(a), [!SIGN_BITS; N]));
+                let swap = simd_lt(abs_x, abs_y);
+                let min = simd_select(swap, abs_x, abs_y);
+                let max = simd_select(swap, abs_y, abs_x);
+
+                // `0 / 0` is NaN
+                let q = simd_select(simd_eq(max, [0.0; N]), [0.0; N], simd_div(min, max));
+                let q2 = simd_mul(q, q);
+
+                let output = simd_mul(q2, [0.02328600773299502; N]);
+                let output = simd_add([-0.09075201792469148; N], output);
+                let output = simd_add([0.18446355750908083; N], simd_mul(q2, output));
+                let output = simd_add([-0.3315446193087245; N], simd_mul(q2, output));
+                let output = simd_mul(q, simd_add([0.9999647984014695; N], simd_mul(q2, output)));
+
+                let output = simd_select(
+                    simd_gt(abs_y, abs_x),
+                    simd_sub([<$ty as Real>::FRAC_PI_2; N], output),
+                    output,
+                );
+
+                let output = simd_select(
+                    simd_ne(simd_and(to_bits(b), [SIGN_BITS; N]), [0; N]),
+                    simd_sub([<$ty as Real>::PI; N], output),
+                    output,
+                );
+
+                // `output` is positive, take the sign of `y`
+                from_bits(simd_or(to_bits(output), simd_and(to_bits(a), [SIGN_BITS; N])))
+            }
+
+            /// Same as `Real::fast_exp`, with the same operations in the same order.
+            #[inline]
+            #[allow(clippy::excessive_precision)]
+            pub unsafe fn fast_exp<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                let t = simd_mul(a, [<$ty as Real>::LOG2_E; N]);
+
+                // rounds towards negative infinity, saturating
+                let i: [$int; N] = simd_as(t);
+                let truncated: [$ty; N] = simd_as(i);
+                let i = simd_select(simd_gt(truncated, t), simd_sub(i, [1; N]), i);
+
+                let f: [$ty; N] = simd_as(i);
+                let f = simd_sub(t, f);
+                let p = simd_add([0.05174499776411005; N], simd_mul(f, [0.01367030945335438; N]));
+                let p = simd_add([0.24160435727009005; N], simd_mul(f, p));
+                let p = simd_add([0.692972922173052; N], simd_mul(f, p));
+                let p = simd_add([1.0000034929076982; N], simd_mul(f, p));
+
+                // `2^i` in two halves, as `i` may exceed the exponent range by one, lanes out of
+                // range are replaced below
+                let i1 = simd_shr(i, [1; N]);
+                let i2 = simd_sub(i, i1);
+                let output = simd_mul(simd_mul(p, exp2i(i1)), exp2i(i2));
+
+                let output = simd_select(
+                    simd_ge(t, [($bias + 1) as $ty; N]),
+                    [<$ty as Real>::INFINITY; N],
+                    output,
+                );
+
+                simd_select(simd_lt(t, [(1 - $bias) as $ty; N]), [0.0; N], output)
+            }
+        }
+
+        impl sealed::Float for $ty {
+            #[inline]
+            unsafe fn simd_sqrt<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::sqrt(a)
+            }
+
+            #[inline]
+            unsafe fn simd_rsqrt<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::rsqrt(a)
+            }
+
+            #[inline]
+            unsafe fn simd_recip<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::recip(a)
+            }
+
+            #[inline]
+            unsafe fn simd_floor<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::floor(a)
+            }
+
+            #[inline]
+            unsafe fn simd_ceil<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::ceil(a)
+            }
+
+            #[inline]
+            unsafe fn simd_round<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::round(a)
+            }
+
+            #[inline]
+            unsafe fn simd_sin<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::sin_cos(a).0
+            }
+
+            #[inline]
+            unsafe fn simd_cos<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::sin_cos(a).1
+            }
+
+            #[inline]
+            unsafe fn simd_sin_cos<const N: usize>(a: [$ty; N]) -> ([$ty; N], [$ty; N]) {
+                $module::sin_cos(a)
+            }
+
+            #[inline]
+            unsafe fn simd_tan<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::tan(a)
+            }
+
+            #[inline]
+            unsafe fn simd_exp<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::exp(a)
+            }
+
+            #[inline]
+            unsafe fn simd_ln<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::ln(a)
+            }
+
+            #[inline]
+            unsafe fn simd_powf<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                $module::powf(a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_fast_rsqrt<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::fast_rsqrt(a)
+            }
+
+            #[inline]
+            unsafe fn simd_fast_recip<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::fast_recip(a)
+            }
+
+            #[inline]
+            unsafe fn simd_fast_sin<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::fast_sin_cos(a).0
+            }
+
+            #[inline]
+            unsafe fn simd_fast_cos<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::fast_sin_cos(a).1
+            }
+
+            #[inline]
+            unsafe fn simd_fast_sin_cos<const N: usize>(a: [$ty; N]) -> ([$ty; N], [$ty; N]) {
+                $module::fast_sin_cos(a)
+            }
+
+            #[inline]
+            unsafe fn simd_fast_atan2<const N: usize>(a: [$ty; N], b: [$ty; N]) -> [$ty; N] {
+                $module::fast_atan2(a, b)
+            }
+
+            #[inline]
+            unsafe fn simd_fast_exp<const N: usize>(a: [$ty; N]) -> [$ty; N] {
+                $module::fast_exp(a)
+            }
+        }
+    }
+}
+
+impl_float! {
+    kernel_f32: f32, u32, i32,
+    mantissa: 23,
+    bias: 127,
+    subnormal_scale: (16777216.0, 24),
+    frac_pi_2: [1.5703125, 4.837512969970703125e-4, 7.549789948768648e-8],
+    ln_2: [6.9313812256e-1, 9.0580006145e-6],
+    exp_range: (-87.33654, 88.72283),
+    sin: [
+        -1.6666666666666666e-1,
+        8.333333333333333e-3,
+        -1.984126984126984e-4,
+        2.7557319223985893e-6,
+        -2.505210838544172e-8
+    ],
+    cos: [
+        4.1666666666666664e-2,
+        -1.388888888888889e-3,
+        2.48015873015873e-5,
+        -2.755731922398589e-7,
+        2.08767569878681e-9
+    ],
+    exp: [
+        5.0e-1,
+        1.6666666666666666e-1,
+        4.1666666666666664e-2,
+        8.333333333333333e-3,
+        1.388888888888889e-3,
+        1.984126984126984e-4
+    ],
+    ln: [
+        3.3333333333333333e-1,
+        2.0e-1,
+        1.4285714285714285e-1,
+        1.1111111111111111e-1,
+        9.090909090909091e-2
+    ],
+    fast_rsqrt: 0x5f37_5a86,
+    fast_recip: 0x7ef3_11c7,
+}
+
+impl_float! {
+    kernel_f64: f64, u64, i64,
+    mantissa: 52,
+    bias: 1023,
+    subnormal_scale: (18014398509481984.0, 54),
+    frac_pi_2: [1.57079632673412561417e0, 6.07710050630396597660e-11, 2.02226624871116645580e-21],
+    ln_2: [6.93147180369123816490e-1, 1.90821492927058770002e-10],
+    exp_range: (-708.3964185322641, 709.782712893384),
+    sin: [
+        -1.6666666666666666e-1,
+        8.333333333333333e-3,
+        -1.984126984126984e-4,
+        2.7557319223985893e-6,
+        -2.505210838544172e-8,
+        1.6059043836821613e-10,
+        -7.647163731819816e-13
+    ],
+    cos: [
+        4.1666666666666664e-2,
+        -1.388888888888889e-3,
+        2.48015873015873e-5,
+        -2.755731922398589e-7,
+        2.08767569878681e-9,
+        -1.1470745597729725e-11,
+        4.779477332387385e-14
+    ],
+    exp: [
+        5.0e-1,
+        1.6666666666666666e-1,
+        4.1666666666666664e-2,
+        8.333333333333333e-3,
+        1.388888888888889e-3,
+        1.984126984126984e-4,
+        2.48015873015873e-5,
+        2.7557319223985893e-6,
+        2.755731922398589e-7,
+        2.505210838544172e-8,
+        2.08767569878681e-9,
+        1.6059043836821613e-10
+    ],
+    ln: [
+        3.3333333333333333e-1,
+        2.0e-1,
+        1.4285714285714285e-1,
+        1.1111111111111111e-1,
+        9.090909090909091e-2,
+        7.692307692307693e-2,
+        6.666666666666667e-2,
+        5.8823529411764705e-2,
+        5.263157894736842e-2,
+        4.7619047619047616e-2
+    ],
+    fast_rsqrt: 0x5fe6_eb50_c7b5_37a9,
+    fast_recip: 0x7fde_6238_2283_5eea,
+}
@@ -0,0 +1,94 @@
+use super::element::Sealed;
+use super::{Element, LaneCount, Lanes, Vec};
+use crate::identity::Zero;
+use crate::intrinsics::const_eval_select;
+use crate::Simd;
+use core::ops::Add;
+
+#[inline]
+#[must_use]
+pub const fn mul_add<T, const N: usize>(mut a: Vec<T, N>, b: Vec<T, N>, c: Vec<T, N>) -> Vec<T, N>
+where
+    T: ~const Element,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    let mut i = 0;
+
+    while i < Vec::<T, N>::CHUNKS {
+        a.set_chunk(i, a.chunk(i).mul_add(b.chunk(i), c.chunk(i)));
+        i += 1;
+    }
+
+    let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+    while i < N {
+        a.array[i] = <T as Sealed>::lane_mul_add(a.array[i], b.array[i], c.array[i]);
+        i += 1;
+    }
+
+    a
+}
+
+#[inline]
+#[must_use]
+pub const fn dot<T, const N: usize>(a: Vec<T, N>, b: Vec<T, N>) -> T
+where
+    T: ~const Element,
+    T: ~const Zero,
+    T: ~const Add<Output = T>,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    // accumulate each lane with a fused multiply-add, then sum the lanes
+    let mut accumulator = Simd::splat(<T as Zero>::zero());
+    let mut i = 0;
+
+    while i < Vec::<T, N>::CHUNKS {
+        accumulator = a.chunk(i).mul_add(b.chunk(i), accumulator);
+        i += 1;
+    }
+
+    let mut output = accumulator.sum();
+    let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+    while i < N {
+        output = <T as Sealed>::lane_mul_add(a.array[i], b.array[i], output);
+        i += 1;
+    }
+
+    output
+}
+
+#[inline]
+#[must_use]
+pub const fn dot_unordered<T, const N: usize>(a: Vec<T, N>, b: Vec<T, N>) -> T
+where
+    T: ~const Element,
+    T: ~const Zero,
+    T: ~const Add<Output = T>,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn dispatch_dot<T, const N: usize>(a: Vec<T, N>, b: Vec<T, N>) -> T
+    where
+        T: Element,
+        T: Zero,
+        T: Add<Output = T>,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        #[cfg(feature = "runtime-dispatch")]
+        if N * core::mem::size_of::<T>() >= super::dispatch::MIN_SIZE {
+            return <T as super::element::Kernel>::dispatch_dot(&a.array, &b.array);
+        }
+
+        dot(a, b)
+    }
+
+    unsafe { const_eval_select!((a, b), dot, dispatch_dot) }
+}
@@ -0,0 +1,63 @@
+use super::Vec;
+use core::array;
+use core::iter::FusedIterator;
+
+/// By-value iterator over the lanes of a vector.
+#[derive(Clone)]
+pub struct IntoIter<T, const N: usize> {
+    iter: array::IntoIter<T, N>,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    #[inline]
+    pub(crate) fn new(vec: Vec<T, N>) -> Self {
+        Self {
+            iter: IntoIterator::into_iter(vec.array),
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.iter.nth_back(n)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
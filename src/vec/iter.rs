@@ -1,9 +1,10 @@
 use super::Vec;
+use core::iter::FusedIterator;
 
 pub struct Iter<'a, T, const N: usize> {
     vec: &'a Vec<T, N>,
     forward: usize,
-    backward: isize,
+    backward: usize,
 }
 
 impl<'a, T, const N: usize> Iter<'a, T, N> {
@@ -12,13 +13,18 @@ impl<'a, T, const N: usize> Iter<'a, T, N> {
         Self {
             vec,
             forward: 0,
-            backward: vec.len() as isize,
+            backward: vec.len(),
         }
     }
 
     #[inline]
     pub const fn len(&self) -> usize {
-        self.vec.len().saturating_sub(self.forward)
+        self.backward - self.forward
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     #[inline]
@@ -27,33 +33,104 @@ impl<'a, T, const N: usize> Iter<'a, T, N> {
     }
 
     #[inline]
-    pub const fn next(&mut self) -> Option<&T> {
+    pub const fn next(&mut self) -> Option<&'a T> {
         self.nth(0)
     }
 
     #[inline]
-    pub const fn nth(&mut self, n: usize) -> Option<&T> {
-        let i = self.forward.saturating_add(n);
+    pub const fn nth(&mut self, n: usize) -> Option<&'a T> {
+        if n >= self.len() {
+            self.forward = self.backward;
+
+            return None;
+        }
 
-        self.forward = i.saturating_add(1);
-        self.vec.get(i)
+        let i = self.forward + n;
+
+        self.forward = i + 1;
+
+        let vec: &'a Vec<T, N> = self.vec;
+
+        Some(&vec.array[i])
     }
 
     #[inline]
-    pub const fn next_back(&mut self) -> Option<&T> {
+    pub const fn next_back(&mut self) -> Option<&'a T> {
         self.nth_back(0)
     }
 
     #[inline]
-    pub const fn nth_back(&mut self, n: usize) -> Option<&T> {
-        let i = self.backward.saturating_sub(n as isize);
+    pub const fn nth_back(&mut self, n: usize) -> Option<&'a T> {
+        if n >= self.len() {
+            self.backward = self.forward;
 
-        self.backward = i.saturating_sub(1);
-
-        if i < 0 {
             return None;
         }
 
-        self.vec.get(i as usize)
+        let i = self.backward - n - 1;
+
+        self.backward = i;
+
+        let vec: &'a Vec<T, N> = self.vec;
+
+        Some(&vec.array[i])
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        Iter::next(self)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        Iter::nth(self, n)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = Iter::len(self);
+
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        Iter::count(self)
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for Iter<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        Iter::next_back(self)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<&'a T> {
+        Iter::nth_back(self, n)
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Iter<'a, T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        Iter::len(self)
+    }
+}
+
+impl<'a, T, const N: usize> FusedIterator for Iter<'a, T, N> {}
+
+impl<'a, T, const N: usize> Clone for Iter<'a, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec,
+            forward: self.forward,
+            backward: self.backward,
+        }
     }
 }
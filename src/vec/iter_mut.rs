@@ -1,26 +1,45 @@
 use super::Vec;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
 
 pub struct IterMut<'a, T, const N: usize> {
-    vec: &'a mut Vec<T, N>,
+    // lanes are indexed from a pointer taken once, as reborrowing the vector would invalidate the
+    // references already yielded
+    ptr: NonNull<T>,
     forward: usize,
-    backward: isize,
+    backward: usize,
+    _marker: PhantomData<&'a mut T>,
 }
 
+// SAFETY: same as `&'a mut [T]`
+unsafe impl<'a, T: Send, const N: usize> Send for IterMut<'a, T, N> {}
+
+// SAFETY: same as `&'a mut [T]`
+unsafe impl<'a, T: Sync, const N: usize> Sync for IterMut<'a, T, N> {}
+
 impl<'a, T, const N: usize> IterMut<'a, T, N> {
     #[inline]
     pub(crate) const fn new(vec: &'a mut Vec<T, N>) -> Self {
-        let backward = vec.len() as isize;
+        // SAFETY: pointers to arrays are never null
+        let ptr = unsafe { NonNull::new_unchecked(vec.array.as_mut_ptr()) };
 
         Self {
-            vec,
+            ptr,
             forward: 0,
-            backward,
+            backward: N,
+            _marker: PhantomData,
         }
     }
 
     #[inline]
     pub const fn len(&self) -> usize {
-        self.vec.len().saturating_sub(self.forward)
+        self.backward - self.forward
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     #[inline]
@@ -29,33 +48,91 @@ impl<'a, T, const N: usize> IterMut<'a, T, N> {
     }
 
     #[inline]
-    pub const fn next(&mut self) -> Option<&mut T> {
+    pub const fn next(&mut self) -> Option<&'a mut T> {
         self.nth(0)
     }
 
     #[inline]
-    pub const fn nth(&mut self, n: usize) -> Option<&mut T> {
-        let i = self.forward.saturating_add(n);
+    pub const fn nth(&mut self, n: usize) -> Option<&'a mut T> {
+        if n >= self.len() {
+            self.forward = self.backward;
+
+            return None;
+        }
+
+        let i = self.forward + n;
+
+        self.forward = i + 1;
 
-        self.forward = i.saturating_add(1);
-        self.vec.get_mut(i)
+        // SAFETY: `i` is within bounds, and each lane is only yielded once
+        unsafe { Some(&mut *self.ptr.as_ptr().add(i)) }
     }
 
     #[inline]
-    pub const fn next_back(&mut self) -> Option<&mut T> {
+    pub const fn next_back(&mut self) -> Option<&'a mut T> {
         self.nth_back(0)
     }
 
     #[inline]
-    pub const fn nth_back(&mut self, n: usize) -> Option<&mut T> {
-        let i = self.backward.saturating_sub(n as isize);
+    pub const fn nth_back(&mut self, n: usize) -> Option<&'a mut T> {
+        if n >= self.len() {
+            self.backward = self.forward;
 
-        self.backward = i.saturating_sub(1);
-
-        if i < 0 {
             return None;
         }
 
-        self.vec.get_mut(i as usize)
+        let i = self.backward - n - 1;
+
+        self.backward = i;
+
+        // SAFETY: `i` is within bounds, and each lane is only yielded once
+        unsafe { Some(&mut *self.ptr.as_ptr().add(i)) }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        IterMut::next(self)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a mut T> {
+        IterMut::nth(self, n)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = IterMut::len(self);
+
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        IterMut::count(self)
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for IterMut<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        IterMut::next_back(self)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<&'a mut T> {
+        IterMut::nth_back(self, n)
     }
 }
+
+impl<'a, T, const N: usize> ExactSizeIterator for IterMut<'a, T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        IterMut::len(self)
+    }
+}
+
+impl<'a, T, const N: usize> FusedIterator for IterMut<'a, T, N> {}
@@ -5,22 +5,96 @@ mod sealed {
     pub trait Sealed {}
 }
 
+/// Width of the widest SIMD registers, in bytes.
+///
+/// Detected from the target features, or set with the `simd-width-16`, `simd-width-32`, and
+/// `simd-width-64` features, the widest enabled feature wins.
+const WIDTH: usize = if cfg!(feature = "simd-width-64") {
+    64
+} else if cfg!(feature = "simd-width-32") {
+    32
+} else if cfg!(feature = "simd-width-16") {
+    16
+} else if cfg!(target_feature = "avx512f") {
+    64
+} else if cfg!(target_feature = "avx2") {
+    32
+} else {
+    // SSE2, NEON, simd128, and targets without SIMD
+    16
+};
+
 /// SIMD lane count.
 pub struct Lanes<T, const N: usize>(PhantomData<[T; N]>);
 
 /// SIMD-able lanes.
+///
+/// `LANES` is the largest power of two not greater than `N`, at least one lane, and at most as
+/// wide as the SIMD registers of the target:
+///
+/// - 64 bytes with AVX-512
+/// - 32 bytes with AVX2
+/// - 16 bytes otherwise, such as SSE2, NEON, and simd128
+///
+/// The `simd-width-16`, `simd-width-32`, and `simd-width-64` features override the width, the
+/// widest enabled feature wins. [`Vec::LANES`] is the lane count of a vector.
+///
+/// With the `runtime-dispatch` feature, which requires `std`, [`Vec::sum_unordered`],
+/// [`Vec::product_unordered`], and [`Vec::dot_unordered`] of vectors of at least 256 bytes
+/// instead use the widest registers the CPU supports, detected once at runtime, so binaries built
+/// for a baseline target still use AVX2, or AVX-512 where present.
+///
+/// With the `scalar` feature, no platform intrinsics are used, every lane is computed by the same
+/// code as in const contexts, for Miri, and targets without SIMD. `LANES` is unchanged, but
+/// unused.
+///
+/// # Examples
+///
+/// ```
+/// use meth::{LaneCount, Lanes, Vec};
+///
+/// assert_eq!(<Lanes<u8, 0> as LaneCount>::LANES, 1);
+/// assert_eq!(<Lanes<u8, 1> as LaneCount>::LANES, 1);
+/// assert_eq!(<Lanes<u8, 6> as LaneCount>::LANES, 4);
+/// assert_eq!(<Lanes<f32, 16> as LaneCount>::LANES, Vec::<f32, 16>::LANES);
+///
+/// assert!((4..=16).contains(&Vec::<f32, 16>::LANES));
+/// assert!((2..=8).contains(&Vec::<f64, 16>::LANES));
+/// assert!((1..=4).contains(&Vec::<u128, 16>::LANES));
+///
+/// macro_rules! check {
+///     ($($ty:ty),*) => {$(
+///         let a = Vec::<$ty, 9>::from_array([1, 2, 3, 4, 5, 6, 7, 8, 9].map(|x| x as $ty));
+///         let b = Vec::<$ty, 9>::splat(2 as $ty);
+///
+///         assert_eq!(*(a + b), [3, 4, 5, 6, 7, 8, 9, 10, 11].map(|x| x as $ty));
+///         assert_eq!(*(a * b), [2, 4, 6, 8, 10, 12, 14, 16, 18].map(|x| x as $ty));
+///         assert_eq!(*a.max(b), [2, 2, 3, 4, 5, 6, 7, 8, 9].map(|x| x as $ty));
+///         assert_eq!(*a.cast::<f64>(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+///         assert_eq!(a.sum(), 45 as $ty);
+///         assert_eq!(a.dot(b), 90 as $ty);
+///         assert_eq!(a.dot_unordered(b), 90 as $ty);
+///         assert!(a.lanes_ge(b).any());
+///
+///         let c = Vec::<$ty, 1>::splat(3 as $ty);
+///
+///         assert_eq!(*(c - c), [0 as $ty]);
+///         assert_eq!(Vec::<$ty, 0>::from_array([]).sum(), 0 as $ty);
+///     )*};
+/// }
+///
+/// check!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+/// ```
 pub trait LaneCount {
     const LANES: usize;
 }
 
 /// Determine the nearest lane to `lanes` for the given `size`.
+///
+/// Vectors are at most `WIDTH` bytes wide, and have at least one lane, so chunks of `LANES`
+/// lanes are never empty.
 const fn nearest_lane(size: usize, lanes: usize) -> usize {
-    let max_lanes = 64
-        / if size < 8 {
-            size
-        } else {
-            panic!("unsupported type");
-        };
+    let max_lanes = if size < WIDTH { WIDTH / size } else { 1 };
 
     let lanes = if lanes.is_power_of_two() {
         lanes
@@ -31,8 +105,8 @@ const fn nearest_lane(size: usize, lanes: usize) -> usize {
         }
     };
 
-    if lanes < 2 {
-        0
+    if lanes < 1 {
+        1
     } else if lanes < max_lanes {
         lanes
     } else {
@@ -57,10 +131,12 @@ impl_lanes! { i8 }
 impl_lanes! { i16 }
 impl_lanes! { i32 }
 impl_lanes! { i64 }
+impl_lanes! { i128 }
 impl_lanes! { isize }
 
 impl_lanes! { u8 }
 impl_lanes! { u16 }
 impl_lanes! { u32 }
 impl_lanes! { u64 }
+impl_lanes! { u128 }
 impl_lanes! { usize }
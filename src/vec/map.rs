@@ -0,0 +1,161 @@
+use super::Vec;
+use core::marker::Destruct;
+use core::mem::MaybeUninit;
+
+impl<T, const N: usize> Vec<T, N> {
+    /// Creates a new vector where each lane is the result of calling `f` with its index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::<usize, 4>::from_fn(|i| i * 2);
+    ///
+    /// assert_eq!(*vec, [0, 2, 4, 6]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_fn<F>(mut f: F) -> Vec<T, N>
+    where
+        F: ~const FnMut(usize) -> T,
+        F: ~const Destruct,
+    {
+        let mut output = MaybeUninit::<[T; N]>::uninit();
+        let ptr = output.as_mut_ptr().cast::<T>();
+        let mut i = 0;
+
+        while i < N {
+            // SAFETY: `i` is within `0..N`
+            unsafe {
+                ptr.add(i).write(f(i));
+            }
+
+            i += 1;
+        }
+
+        // SAFETY: every lane was written above
+        Vec::from_array(unsafe { output.assume_init() })
+    }
+
+    /// Creates a new vector where each lane is the result of calling `f` with the lane of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([1, 2, 3]).map(|x| x * x);
+    ///
+    /// assert_eq!(*vec, [1, 4, 9]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn map<U, F>(self, mut f: F) -> Vec<U, N>
+    where
+        T: Copy,
+        F: ~const FnMut(T) -> U,
+        F: ~const Destruct,
+    {
+        let mut output = MaybeUninit::<[U; N]>::uninit();
+        let ptr = output.as_mut_ptr().cast::<U>();
+        let mut i = 0;
+
+        while i < N {
+            // SAFETY: `i` is within `0..N`
+            unsafe {
+                ptr.add(i).write(f(self.array[i]));
+            }
+
+            i += 1;
+        }
+
+        // SAFETY: every lane was written above
+        Vec::from_array(unsafe { output.assume_init() })
+    }
+
+    /// Creates a new vector where each lane is the result of calling `f` with the lanes of `self`,
+    /// and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let a = Vec::from_array([1, 2, 3]);
+    /// let b = Vec::from_array([4, 5, 6]);
+    ///
+    /// assert_eq!(*a.zip_with(b, |a, b| a * 10 + b), [14, 25, 36]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn zip_with<U, V, F>(self, other: Vec<U, N>, mut f: F) -> Vec<V, N>
+    where
+        T: Copy,
+        U: Copy,
+        F: ~const FnMut(T, U) -> V,
+        F: ~const Destruct,
+    {
+        let mut output = MaybeUninit::<[V; N]>::uninit();
+        let ptr = output.as_mut_ptr().cast::<V>();
+        let mut i = 0;
+
+        while i < N {
+            // SAFETY: `i` is within `0..N`
+            unsafe {
+                ptr.add(i).write(f(self.array[i], other.array[i]));
+            }
+
+            i += 1;
+        }
+
+        // SAFETY: every lane was written above
+        Vec::from_array(unsafe { output.assume_init() })
+    }
+
+    /// Folds every lane into an accumulator, starting with `init`, in order of the lanes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([1, 2, 3]);
+    ///
+    /// assert_eq!(vec.fold(0, |acc, x| acc * 10 + x), 123);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn fold<A, F>(self, init: A, mut f: F) -> A
+    where
+        T: Copy,
+        F: ~const FnMut(A, T) -> A,
+        F: ~const Destruct,
+    {
+        let mut output = init;
+        let mut i = 0;
+
+        while i < N {
+            output = f(output, self.array[i]);
+            i += 1;
+        }
+
+        output
+    }
+
+    /// Calls `f` with every lane, in order of the lanes.
+    #[inline]
+    pub const fn for_each<F>(self, mut f: F)
+    where
+        T: Copy,
+        F: ~const FnMut(T),
+        F: ~const Destruct,
+    {
+        let mut i = 0;
+
+        while i < N {
+            f(self.array[i]);
+            i += 1;
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use super::{Element, LaneCount, Lanes, Vec};
+use crate::intrinsics;
+use crate::intrinsics::const_eval_select;
+use core::fmt;
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+use core::ops::{BitAndAssign, BitOrAssign, BitXorAssign};
+
+/// Lane-wise boolean mask, the result of comparing two vectors.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Mask<const N: usize> {
+    array: [bool; N],
+}
+
+impl<const N: usize> Mask<N> {
+    /// Converts an array to a mask.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(array: [bool; N]) -> Mask<N> {
+        Self { array }
+    }
+
+    /// Converts a mask to an array.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [bool; N] {
+        self.array
+    }
+
+    /// Creates a new mask with all lanes set to the given value.
+    #[inline]
+    #[must_use]
+    pub const fn splat(value: bool) -> Mask<N> {
+        Self::from_array([value; N])
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the value of lane `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[must_use]
+    pub const fn test(&self, index: usize) -> bool {
+        self.array[index]
+    }
+
+    /// Sets the value of lane `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub const fn set(&mut self, index: usize, value: bool) {
+        self.array[index] = value;
+    }
+
+    /// Returns `true` if all lanes are set.
+    #[inline]
+    #[must_use]
+    pub const fn all(self) -> bool {
+        let mut i = 0;
+
+        while i < N {
+            if !self.array[i] {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        true
+    }
+
+    /// Returns `true` if any lane is set.
+    #[inline]
+    #[must_use]
+    pub const fn any(self) -> bool {
+        let mut i = 0;
+
+        while i < N {
+            if self.array[i] {
+                return true;
+            }
+
+            i += 1;
+        }
+
+        false
+    }
+
+    /// Chooses lanes from `if_true` where the mask is set, and from `if_false` where it is not.
+    #[inline]
+    #[must_use]
+    pub const fn select<T>(self, if_true: Vec<T, N>, if_false: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        // called in const contexts
+        #[inline]
+        #[must_use]
+        const fn scalar_select<T, const N: usize>(
+            mask: Mask<N>,
+            mut a: Vec<T, N>,
+            b: Vec<T, N>,
+        ) -> Vec<T, N>
+        where
+            T: ~const Element,
+            Lanes<T, N>: LaneCount,
+            [(); Lanes::<T, N>::LANES]:,
+        {
+            let mut i = 0;
+
+            while i < N {
+                if !mask.array[i] {
+                    a.array[i] = b.array[i];
+                }
+
+                i += 1;
+            }
+
+            a
+        }
+
+        // called in non-const contexts
+        #[inline]
+        #[must_use]
+        #[cfg_attr(feature = "scalar", allow(dead_code))]
+        fn simd_select<T, const N: usize>(mask: Mask<N>, mut a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
+        where
+            T: Element,
+            Lanes<T, N>: LaneCount,
+            [(); Lanes::<T, N>::LANES]:,
+        {
+            unsafe {
+                let mut mask_iter = mask.array.chunks_exact(<Lanes<T, N> as LaneCount>::LANES);
+                let mut a_iter = a.array.chunks_exact_mut(<Lanes<T, N> as LaneCount>::LANES);
+                let mut b_iter = b.array.chunks_exact(<Lanes<T, N> as LaneCount>::LANES);
+
+                while let (Some(mask), Some(a), Some(b)) =
+                    (mask_iter.next(), a_iter.next(), b_iter.next())
+                {
+                    let mask = mask
+                        .as_ptr()
+                        .cast::<[bool; <Lanes<T, N> as LaneCount>::LANES]>();
+                    let a = a.as_mut_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
+                    let b = b.as_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
+
+                    *a = intrinsics::simd_select(*mask, *a, *b);
+                }
+
+                let mut mask_iter = mask_iter.remainder().iter();
+                let mut a_iter = a_iter.into_remainder().iter_mut();
+                let mut b_iter = b_iter.remainder().iter();
+
+                while let (Some(mask), Some(a), Some(b)) =
+                    (mask_iter.next(), a_iter.next(), b_iter.next())
+                {
+                    if !*mask {
+                        *a = *b;
+                    }
+                }
+
+                a
+            }
+        }
+
+        unsafe { const_eval_select!((self, if_true, if_false), scalar_select, simd_select) }
+    }
+}
+
+macro_rules! impl_op {
+    { $trait:ident, $trait_assign:ident, $fn:ident, $fn_assign:ident, $op:tt } => {
+        impl<const N: usize> const $trait for Mask<N> {
+            type Output = Mask<N>;
+
+            #[inline]
+            #[must_use]
+            fn $fn(mut self, other: Mask<N>) -> Mask<N> {
+                let mut i = 0;
+
+                while i < N {
+                    self.array[i] = self.array[i] $op other.array[i];
+                    i += 1;
+                }
+
+                self
+            }
+        }
+
+        impl<const N: usize> const $trait_assign for Mask<N> {
+            #[inline]
+            fn $fn_assign(&mut self, other: Mask<N>) {
+                *self = *self $op other;
+            }
+        }
+    }
+}
+
+impl_op! { BitAnd, BitAndAssign, bitand, bitand_assign, & }
+impl_op! { BitOr, BitOrAssign, bitor, bitor_assign, | }
+impl_op! { BitXor, BitXorAssign, bitxor, bitxor_assign, ^ }
+
+impl<const N: usize> const Not for Mask<N> {
+    type Output = Mask<N>;
+
+    #[inline]
+    #[must_use]
+    fn not(mut self) -> Mask<N> {
+        let mut i = 0;
+
+        while i < N {
+            self.array[i] = !self.array[i];
+            i += 1;
+        }
+
+        self
+    }
+}
+
+impl<const N: usize> fmt::Debug for Mask<N> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.array, fmt)
+    }
+}
@@ -0,0 +1,456 @@
+use super::float::Float;
+use super::{Element, LaneCount, Lanes, Vec};
+use crate::Real;
+use crate::intrinsics::const_eval_select;
+use crate::Simd;
+
+macro_rules! impl_unary {
+    { $fn:ident, $fn_scalar:ident, $fn_simd:ident, |$x:ident| $lane:expr } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const N: usize>(vec: Vec<T, N>) -> Vec<T, N>
+        where
+            T: ~const Element,
+            T: ~const Real,
+            T: Float,
+            Lanes<T, N>: LaneCount,
+            [(); Lanes::<T, N>::LANES]:,
+        {
+            // called in const contexts
+            #[inline]
+            #[must_use]
+            const fn $fn_scalar<T, const N: usize>(mut vec: Vec<T, N>) -> Vec<T, N>
+            where
+                T: ~const Element,
+                T: ~const Real,
+                T: Float,
+                Lanes<T, N>: LaneCount,
+                [(); Lanes::<T, N>::LANES]:,
+            {
+                let mut iter = vec.iter_mut();
+
+                while let Some(element) = iter.next() {
+                    let $x = *element;
+
+                    *element = $lane;
+                }
+
+                vec
+            }
+
+            // called in non-const contexts
+            #[inline]
+            #[must_use]
+            #[cfg_attr(feature = "scalar", allow(dead_code))]
+            fn $fn_simd<T, const N: usize>(mut vec: Vec<T, N>) -> Vec<T, N>
+            where
+                T: Element,
+                T: Real,
+                T: Float,
+                Lanes<T, N>: LaneCount,
+                [(); Lanes::<T, N>::LANES]:,
+            {
+                unsafe {
+                    let mut iter = vec.array.chunks_exact_mut(<Lanes<T, N> as LaneCount>::LANES);
+
+                    while let Some(element) = iter.next() {
+                        let element = element
+                            .as_mut_ptr()
+                            .cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
+
+                        *element = <T as Float>::$fn_simd(*element);
+                    }
+
+                    // the remainder uses the same kernel, so every lane is consistent
+                    let remainder = iter.into_remainder();
+                    let mut chunk = [<T as Real>::NAN; <Lanes<T, N> as LaneCount>::LANES];
+
+                    chunk[..remainder.len()].copy_from_slice(remainder);
+                    chunk = <T as Float>::$fn_simd(chunk);
+                    remainder.copy_from_slice(&chunk[..remainder.len()]);
+
+                    vec
+                }
+            }
+
+            unsafe { const_eval_select!((vec,), $fn_scalar, $fn_simd) }
+        }
+    }
+}
+
+impl_unary! { sqrt, scalar_sqrt, simd_sqrt, |x| <T as Real>::sqrt(x) }
+impl_unary! { rsqrt, scalar_rsqrt, simd_rsqrt, |x| <T as Real>::recip(<T as Real>::sqrt(x)) }
+impl_unary! { recip, scalar_recip, simd_recip, |x| <T as Real>::recip(x) }
+impl_unary! { floor, scalar_floor, simd_floor, |x| <T as Real>::floor(x) }
+impl_unary! { ceil, scalar_ceil, simd_ceil, |x| <T as Real>::ceil(x) }
+impl_unary! { round, scalar_round, simd_round, |x| <T as Real>::round(x) }
+impl_unary! { sin, scalar_sin, simd_sin, |x| <T as Real>::sin(x) }
+impl_unary! { cos, scalar_cos, simd_cos, |x| <T as Real>::cos(x) }
+impl_unary! { tan, scalar_tan, simd_tan, |x| <T as Real>::tan(x) }
+impl_unary! { exp, scalar_exp, simd_exp, |x| <T as Real>::exp(x) }
+impl_unary! { ln, scalar_ln, simd_ln, |x| <T as Real>::ln(x) }
+
+#[inline]
+#[must_use]
+pub const fn sin_cos<T, const N: usize>(vec: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: Float,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_sin_cos<T, const N: usize>(mut sin: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut cos = sin;
+        let mut sin_iter = sin.iter_mut();
+        let mut cos_iter = cos.iter_mut();
+
+        while let (Some(sin), Some(cos)) = (sin_iter.next(), cos_iter.next()) {
+            (*sin, *cos) = <T as Real>::sin_cos(*sin);
+        }
+
+        (sin, cos)
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_sin_cos<T, const N: usize>(mut sin: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: Element,
+        T: Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut cos = sin;
+
+        unsafe {
+            let mut sin_iter = sin.array.chunks_exact_mut(<Lanes<T, N> as LaneCount>::LANES);
+            let mut cos_iter = cos.array.chunks_exact_mut(<Lanes<T, N> as LaneCount>::LANES);
+
+            while let (Some(sin), Some(cos)) = (sin_iter.next(), cos_iter.next()) {
+                let sin = sin.as_mut_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
+                let cos = cos.as_mut_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
+
+                (*sin, *cos) = <T as Float>::simd_sin_cos(*sin);
+            }
+
+            // the remainder uses the same kernel, so every lane is consistent
+            let sin_remainder = sin_iter.into_remainder();
+            let cos_remainder = cos_iter.into_remainder();
+            let len = sin_remainder.len();
+            let mut chunk = [<T as Real>::NAN; <Lanes<T, N> as LaneCount>::LANES];
+
+            chunk[..len].copy_from_slice(sin_remainder);
+
+            let (sin_chunk, cos_chunk) = <T as Float>::simd_sin_cos(chunk);
+
+            sin_remainder.copy_from_slice(&sin_chunk[..len]);
+            cos_remainder.copy_from_slice(&cos_chunk[..len]);
+        }
+
+        (sin, cos)
+    }
+
+    unsafe { const_eval_select!((vec,), scalar_sin_cos, simd_sin_cos) }
+}
+
+#[inline]
+#[must_use]
+pub const fn powf<T, const N: usize>(a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: Float,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_powf<T, const N: usize>(mut a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut a_iter = a.iter_mut();
+        let mut b_iter = b.iter();
+
+        while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
+            *a = <T as Real>::powf(*a, *b);
+        }
+
+        a
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_powf<T, const N: usize>(mut a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
+    where
+        T: Element,
+        T: Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        unsafe {
+            let mut a_iter = a.array.chunks_exact_mut(<Lanes<T, N> as LaneCount>::LANES);
+            let mut b_iter = b.array.chunks_exact(<Lanes<T, N> as LaneCount>::LANES);
+
+            while let (Some(a), Some(b)) = (a_iter.next(), b_iter.next()) {
+                let a = a.as_mut_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
+                let b = b.as_ptr().cast::<[T; <Lanes<T, N> as LaneCount>::LANES]>();
+
+                *a = <T as Float>::simd_powf(*a, *b);
+            }
+
+            // the remainder uses the same kernel, so every lane is consistent
+            let a_remainder = a_iter.into_remainder();
+            let b_remainder = b_iter.remainder();
+            let len = a_remainder.len();
+            let mut a_chunk = [<T as Real>::NAN; <Lanes<T, N> as LaneCount>::LANES];
+            let mut b_chunk = [<T as Real>::NAN; <Lanes<T, N> as LaneCount>::LANES];
+
+            a_chunk[..len].copy_from_slice(a_remainder);
+            b_chunk[..len].copy_from_slice(b_remainder);
+            a_remainder.copy_from_slice(&<T as Float>::simd_powf(a_chunk, b_chunk)[..len]);
+
+            a
+        }
+    }
+
+    unsafe { const_eval_select!((a, b), scalar_powf, simd_powf) }
+}
+
+macro_rules! impl_fast_unary {
+    { $fn:ident, $fn_scalar:ident, $fn_simd:ident } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const N: usize>(vec: Vec<T, N>) -> Vec<T, N>
+        where
+            T: ~const Element,
+            T: ~const Real,
+            T: Float,
+            Lanes<T, N>: LaneCount,
+            [(); Lanes::<T, N>::LANES]:,
+        {
+            // called in const contexts
+            #[inline]
+            #[must_use]
+            const fn $fn_scalar<T, const N: usize>(mut vec: Vec<T, N>) -> Vec<T, N>
+            where
+                T: ~const Element,
+                T: ~const Real,
+                T: Float,
+                Lanes<T, N>: LaneCount,
+                [(); Lanes::<T, N>::LANES]:,
+            {
+                let mut iter = vec.iter_mut();
+
+                while let Some(element) = iter.next() {
+                    *element = <T as Real>::$fn(*element);
+                }
+
+                vec
+            }
+
+            // called in non-const contexts
+            #[inline]
+            #[must_use]
+            #[cfg_attr(feature = "scalar", allow(dead_code))]
+            fn $fn_simd<T, const N: usize>(mut vec: Vec<T, N>) -> Vec<T, N>
+            where
+                T: Element,
+                T: Real,
+                T: Float,
+                Lanes<T, N>: LaneCount,
+                [(); Lanes::<T, N>::LANES]:,
+            {
+                let mut i = 0;
+
+                while i < Vec::<T, N>::CHUNKS {
+                    // SAFETY: the kernels are plain lane-wise arithmetic
+                    let chunk = unsafe { <T as Float>::$fn_simd(vec.chunk(i).to_array()) };
+
+                    vec.set_chunk(i, Simd::from_array(chunk));
+                    i += 1;
+                }
+
+                // the kernels use the same operations as `Real`, so the remainder is consistent
+                let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+                while i < N {
+                    vec.array[i] = <T as Real>::$fn(vec.array[i]);
+                    i += 1;
+                }
+
+                vec
+            }
+
+            unsafe { const_eval_select!((vec,), $fn_scalar, $fn_simd) }
+        }
+    }
+}
+
+impl_fast_unary! { fast_rsqrt, scalar_fast_rsqrt, simd_fast_rsqrt }
+impl_fast_unary! { fast_recip, scalar_fast_recip, simd_fast_recip }
+impl_fast_unary! { fast_sin, scalar_fast_sin, simd_fast_sin }
+impl_fast_unary! { fast_cos, scalar_fast_cos, simd_fast_cos }
+impl_fast_unary! { fast_exp, scalar_fast_exp, simd_fast_exp }
+
+#[inline]
+#[must_use]
+pub const fn fast_sin_cos<T, const N: usize>(vec: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: Float,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_fast_sin_cos<T, const N: usize>(mut sin: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut cos = sin;
+        let mut sin_iter = sin.iter_mut();
+        let mut cos_iter = cos.iter_mut();
+
+        while let (Some(sin), Some(cos)) = (sin_iter.next(), cos_iter.next()) {
+            (*sin, *cos) = <T as Real>::fast_sin_cos(*sin);
+        }
+
+        (sin, cos)
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_fast_sin_cos<T, const N: usize>(mut sin: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: Element,
+        T: Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut cos = sin;
+        let mut i = 0;
+
+        while i < Vec::<T, N>::CHUNKS {
+            // SAFETY: the kernels are plain lane-wise arithmetic
+            let (sin_chunk, cos_chunk) =
+                unsafe { <T as Float>::simd_fast_sin_cos(sin.chunk(i).to_array()) };
+
+            sin.set_chunk(i, Simd::from_array(sin_chunk));
+            cos.set_chunk(i, Simd::from_array(cos_chunk));
+            i += 1;
+        }
+
+        // the kernels use the same operations as `Real`, so the remainder is consistent
+        let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+        while i < N {
+            (sin.array[i], cos.array[i]) = <T as Real>::fast_sin_cos(sin.array[i]);
+            i += 1;
+        }
+
+        (sin, cos)
+    }
+
+    unsafe { const_eval_select!((vec,), scalar_fast_sin_cos, simd_fast_sin_cos) }
+}
+
+#[inline]
+#[must_use]
+pub const fn fast_atan2<T, const N: usize>(y: Vec<T, N>, x: Vec<T, N>) -> Vec<T, N>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: Float,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    // called in const contexts
+    #[inline]
+    #[must_use]
+    const fn scalar_fast_atan2<T, const N: usize>(mut y: Vec<T, N>, x: Vec<T, N>) -> Vec<T, N>
+    where
+        T: ~const Element,
+        T: ~const Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut y_iter = y.iter_mut();
+        let mut x_iter = x.iter();
+
+        while let (Some(y), Some(x)) = (y_iter.next(), x_iter.next()) {
+            *y = <T as Real>::fast_atan2(*y, *x);
+        }
+
+        y
+    }
+
+    // called in non-const contexts
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_fast_atan2<T, const N: usize>(mut y: Vec<T, N>, x: Vec<T, N>) -> Vec<T, N>
+    where
+        T: Element,
+        T: Real,
+        T: Float,
+        Lanes<T, N>: LaneCount,
+        [(); Lanes::<T, N>::LANES]:,
+    {
+        let mut i = 0;
+
+        while i < Vec::<T, N>::CHUNKS {
+            // SAFETY: the kernels are plain lane-wise arithmetic
+            let chunk = unsafe {
+                <T as Float>::simd_fast_atan2(y.chunk(i).to_array(), x.chunk(i).to_array())
+            };
+
+            y.set_chunk(i, Simd::from_array(chunk));
+            i += 1;
+        }
+
+        // the kernels use the same operations as `Real`, so the remainder is consistent
+        let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+        while i < N {
+            y.array[i] = <T as Real>::fast_atan2(y.array[i], x.array[i]);
+            i += 1;
+        }
+
+        y
+    }
+
+    unsafe { const_eval_select!((y, x), scalar_fast_atan2, simd_fast_atan2) }
+}
@@ -0,0 +1,59 @@
+use super::element::Sealed;
+use super::{Element, LaneCount, Lanes, Vec};
+
+macro_rules! impl_minmax {
+    { $fn:ident, $fn_lane:ident } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const N: usize>(mut a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, N>
+        where
+            T: ~const Element,
+            Lanes<T, N>: LaneCount,
+            [(); Lanes::<T, N>::LANES]:,
+        {
+            let mut i = 0;
+
+            while i < Vec::<T, N>::CHUNKS {
+                a.set_chunk(i, a.chunk(i).$fn(b.chunk(i)));
+                i += 1;
+            }
+
+            let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+            while i < N {
+                a.array[i] = <T as Sealed>::$fn_lane(a.array[i], b.array[i]);
+                i += 1;
+            }
+
+            a
+        }
+    }
+}
+
+impl_minmax! { min, lane_min }
+impl_minmax! { max, lane_max }
+
+#[inline]
+#[must_use]
+pub const fn abs<T, const N: usize>(mut vec: Vec<T, N>) -> Vec<T, N>
+where
+    T: ~const Element,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    let mut i = 0;
+
+    while i < Vec::<T, N>::CHUNKS {
+        vec.set_chunk(i, vec.chunk(i).abs());
+        i += 1;
+    }
+
+    let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+    while i < N {
+        vec.array[i] = <T as Sealed>::lane_abs(vec.array[i]);
+        i += 1;
+    }
+
+    vec
+}
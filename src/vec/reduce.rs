@@ -0,0 +1,55 @@
+use super::element::Sealed;
+use super::{Element, LaneCount, Lanes, Vec};
+use core::cmp::PartialOrd;
+use core::ops::{BitAnd, BitOr, BitXor};
+
+macro_rules! impl_reduce {
+    {
+        $fn:ident, $trait:ident $(<Output = $output:ident>)?,
+        |$a:ident, $b:ident| $combine:expr
+    } => {
+        #[inline]
+        #[must_use]
+        pub const fn $fn<T, const N: usize>(vec: Vec<T, N>) -> T
+        where
+            T: ~const Element,
+            T: ~const $trait $(<Output = $output>)?,
+            Lanes<T, N>: LaneCount,
+            [(); Lanes::<T, N>::LANES]:,
+        {
+            // `LANES` is at most `N`, so only empty vectors have no chunks
+            if Vec::<T, N>::CHUNKS == 0 {
+                panic!("reduction of an empty vector");
+            }
+
+            let mut output = vec.chunk(0).$fn();
+            let mut i = 1;
+
+            while i < Vec::<T, N>::CHUNKS {
+                let $a = output;
+                let $b = vec.chunk(i).$fn();
+
+                output = $combine;
+                i += 1;
+            }
+
+            let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+            while i < N {
+                let $a = output;
+                let $b = vec.array[i];
+
+                output = $combine;
+                i += 1;
+            }
+
+            output
+        }
+    }
+}
+
+impl_reduce! { min_element, PartialOrd, |a, b| <T as Sealed>::lane_min(a, b) }
+impl_reduce! { max_element, PartialOrd, |a, b| <T as Sealed>::lane_max(a, b) }
+impl_reduce! { reduce_and, BitAnd<Output = T>, |a, b| a & b }
+impl_reduce! { reduce_or, BitOr<Output = T>, |a, b| a | b }
+impl_reduce! { reduce_xor, BitXor<Output = T>, |a, b| a ^ b }
@@ -0,0 +1,132 @@
+use super::Vec;
+use core::mem::MaybeUninit;
+
+/// Copies `len` lanes from `src` to `dst`, starting at lane `offset` of `dst`.
+#[inline]
+const unsafe fn copy<T>(src: *const T, dst: *mut T, offset: usize, len: usize)
+where
+    T: Copy,
+{
+    let mut i = 0;
+
+    while i < len {
+        dst.add(offset + i).write(src.add(i).read());
+        i += 1;
+    }
+}
+
+impl<T, const N: usize> Vec<T, N> {
+    /// Creates a new vector with `value` appended as the last lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([1, 2, 3]);
+    ///
+    /// assert_eq!(*vec.extend(4), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn extend(self, value: T) -> Vec<T, { N + 1 }>
+    where
+        T: Copy,
+        [(); N + 1]:,
+    {
+        self.concat(Vec::from_array([value]))
+    }
+
+    /// Creates a new vector from the first `M` lanes.
+    ///
+    /// `M` is checked at compile time to not exceed `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(*vec.truncate::<3>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate<const M: usize>(self) -> Vec<T, M>
+    where
+        T: Copy,
+        [(); N - M]:,
+    {
+        self.split::<M>().0
+    }
+
+    /// Creates a new vector with the lanes of `other` appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let a = Vec::from_array([1, 2]);
+    /// let b = Vec::from_array([3, 4, 5]);
+    ///
+    /// assert_eq!(*a.concat(b), [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn concat<const M: usize>(self, other: Vec<T, M>) -> Vec<T, { N + M }>
+    where
+        T: Copy,
+        [(); N + M]:,
+    {
+        let mut output = MaybeUninit::<[T; N + M]>::uninit();
+        let ptr = output.as_mut_ptr().cast::<T>();
+
+        // SAFETY: `N + M` lanes are written in total
+        unsafe {
+            copy(self.array.as_ptr(), ptr, 0, N);
+            copy(other.array.as_ptr(), ptr, N, M);
+
+            Vec::from_array(output.assume_init())
+        }
+    }
+
+    /// Splits the vector into the first `M` lanes, and the remaining `N - M` lanes.
+    ///
+    /// `M` is checked at compile time to not exceed `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([1, 2, 3, 4, 5]);
+    /// let (a, b) = vec.split::<2>();
+    ///
+    /// assert_eq!(*a, [1, 2]);
+    /// assert_eq!(*b, [3, 4, 5]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn split<const M: usize>(self) -> (Vec<T, M>, Vec<T, { N - M }>)
+    where
+        T: Copy,
+        [(); N - M]:,
+    {
+        let mut a = MaybeUninit::<[T; M]>::uninit();
+        let mut b = MaybeUninit::<[T; N - M]>::uninit();
+
+        // SAFETY: `N - M` does not underflow, so `M` lanes, and `N - M` lanes are within bounds
+        unsafe {
+            let ptr = self.array.as_ptr();
+
+            copy(ptr, a.as_mut_ptr().cast::<T>(), 0, M);
+            copy(ptr.add(M), b.as_mut_ptr().cast::<T>(), 0, N - M);
+
+            (
+                Vec::from_array(a.assume_init()),
+                Vec::from_array(b.assume_init()),
+            )
+        }
+    }
+}
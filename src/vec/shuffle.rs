@@ -0,0 +1,168 @@
+use super::{Element, Vec};
+use crate::intrinsics::ShuffleIndex;
+use crate::simd::shuffle::{Const, Deinterleave, Interleave, Reverse, RotateLeft, RotateRight};
+use crate::simd::shuffle::{Validate, ValidateLanes};
+use crate::Simd;
+
+/// Selects lanes from the concatenation of `a` and `b`.
+///
+/// Both vectors are shuffled as a single [`Simd`], so `N`, and `M` must be within `1..=32768`.
+#[inline]
+#[must_use]
+const fn shuffle<T, I, const N: usize, const M: usize>(a: Vec<T, N>, b: Vec<T, N>) -> Vec<T, M>
+where
+    T: ~const Element,
+    I: ShuffleIndex<M>,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = ValidateLanes::<N>::VALID;
+
+    #[allow(clippy::let_unit_value)]
+    let () = ValidateLanes::<M>::VALID;
+
+    let a = Simd::from_array(a.array);
+    let b = Simd::from_array(b.array);
+
+    Vec::from_array(crate::simd::shuffle::shuffle::<T, I, N, M>(a, b).to_array())
+}
+
+impl<T, const N: usize> Vec<T, N> {
+    /// Creates a new vector from the lanes of `self` at `INDEX`.
+    ///
+    /// Indices are checked at compile time to be within `0..N`, and `N`, and `M` to be within
+    /// `1..=32768`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(*vec.shuffle::<2, { [3, 0] }>(), [4, 1]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn shuffle<const M: usize, const INDEX: [usize; M]>(self) -> Vec<T, M>
+    where
+        T: ~const Element,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Validate::<Const<M, INDEX>, M, N, 1>::VALID;
+
+        shuffle::<T, Const<M, INDEX>, N, M>(self, self)
+    }
+
+    /// Rotates lanes left by `OFFSET`, such that lane `OFFSET` becomes the first lane.
+    ///
+    /// Fails to compile if `N` is zero, or above 32768.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_lanes_left<const OFFSET: usize>(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+    {
+        shuffle::<T, RotateLeft<N, OFFSET>, N, N>(self, self)
+    }
+
+    /// Rotates lanes right by `OFFSET`, such that the first lane becomes lane `OFFSET`.
+    ///
+    /// Fails to compile if `N` is zero, or above 32768.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_lanes_right<const OFFSET: usize>(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+    {
+        shuffle::<T, RotateRight<N, OFFSET>, N, N>(self, self)
+    }
+
+    /// Reverses the order of lanes.
+    ///
+    /// Fails to compile if `N` is zero, or above 32768.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::from_array([1, 2, 3]);
+    ///
+    /// assert_eq!(*vec.reverse(), [3, 2, 1]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use meth::Vec;
+    ///
+    /// let vec = Vec::<f32, 0>::from_array([]);
+    ///
+    /// // shuffled vectors must have between 1 and 32768 lanes
+    /// let _ = vec.reverse();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn reverse(self) -> Vec<T, N>
+    where
+        T: ~const Element,
+    {
+        shuffle::<T, Reverse<N>, N, N>(self, self)
+    }
+
+    /// Interleaves lanes of `self` and `other`.
+    ///
+    /// The first vector holds the low half of the interleaved lanes, and the second vector holds
+    /// the high half. Fails to compile if `N` is zero, or above 32768.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let a = Vec::from_array([0, 1, 2, 3]);
+    /// let b = Vec::from_array([4, 5, 6, 7]);
+    /// let (lo, hi) = a.interleave(b);
+    ///
+    /// assert_eq!(*lo, [0, 4, 1, 5]);
+    /// assert_eq!(*hi, [2, 6, 3, 7]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn interleave(self, other: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: ~const Element,
+    {
+        (
+            shuffle::<T, Interleave<N, 0>, N, N>(self, other),
+            shuffle::<T, Interleave<N, N>, N, N>(self, other),
+        )
+    }
+
+    /// Deinterleaves lanes of `self` and `other`, the inverse of [`interleave`](Self::interleave).
+    ///
+    /// The first vector holds the even lanes, and the second vector holds the odd lanes. Fails to
+    /// compile if `N` is zero, or above 32768.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meth::Vec;
+    ///
+    /// let a = Vec::from_array([0, 4, 1, 5]);
+    /// let b = Vec::from_array([2, 6, 3, 7]);
+    /// let (even, odd) = a.deinterleave(b);
+    ///
+    /// assert_eq!(*even, [0, 1, 2, 3]);
+    /// assert_eq!(*odd, [4, 5, 6, 7]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn deinterleave(self, other: Vec<T, N>) -> (Vec<T, N>, Vec<T, N>)
+    where
+        T: ~const Element,
+    {
+        (
+            shuffle::<T, Deinterleave<N, 0>, N, N>(self, other),
+            shuffle::<T, Deinterleave<N, 1>, N, N>(self, other),
+        )
+    }
+}
@@ -1,12 +1,39 @@
 use super::{Element, LaneCount, Lanes, Vec};
 use crate::identity::Zero;
-use crate::intrinsics;
-use core::intrinsics::const_eval_select;
+use crate::intrinsics::const_eval_select;
 use core::ops::Add;
 
 #[inline]
 #[must_use]
 pub const fn sum<T, const N: usize>(vec: Vec<T, N>) -> T
+where
+    T: ~const Element,
+    T: ~const Zero,
+    T: ~const Add<Output = T>,
+    Lanes<T, N>: LaneCount,
+    [(); Lanes::<T, N>::LANES]:,
+{
+    let mut output = <T as Zero>::zero();
+    let mut i = 0;
+
+    while i < Vec::<T, N>::CHUNKS {
+        output = output + vec.chunk(i).sum();
+        i += 1;
+    }
+
+    let mut i = Vec::<T, N>::CHUNKS * Vec::<T, N>::LANES;
+
+    while i < N {
+        output = output + vec.array[i];
+        i += 1;
+    }
+
+    output
+}
+
+#[inline]
+#[must_use]
+pub const fn sum_unordered<T, const N: usize>(vec: Vec<T, N>) -> T
 where
     T: ~const Element,
     T: ~const Zero,
@@ -36,7 +63,8 @@ where
 
     #[inline]
     #[must_use]
-    fn simd_sum<T, const N: usize>(vec: Vec<T, N>) -> T
+    #[cfg_attr(feature = "scalar", allow(dead_code))]
+    fn simd_sum_unordered<T, const N: usize>(mut vec: Vec<T, N>) -> T
     where
         T: Element,
         T: Zero,
@@ -44,25 +72,41 @@ where
         Lanes<T, N>: LaneCount,
         [(); Lanes::<T, N>::LANES]:,
     {
-        unsafe {
-            let mut output = <T as Zero>::zero();
-            let mut iter = vec.array.chunks_exact(<Lanes<T, N> as LaneCount>::LANES);
+        #[cfg(feature = "runtime-dispatch")]
+        if N * core::mem::size_of::<T>() >= super::dispatch::MIN_SIZE {
+            return <T as super::element::Kernel>::dispatch_sum_unordered(&vec.array);
+        }
 
-            while let Some(element) = iter.next() {
-                let element = element.as_ptr() as *const [T; <Lanes<T, N> as LaneCount>::LANES];
+        let chunks = Vec::<T, N>::CHUNKS;
 
-                output = output + intrinsics::simd_sum(*element);
-            }
+        // pairwise tree reduction of the chunks into the first chunk
+        let mut stride = 1;
 
-            let mut iter = iter.remainder().iter();
+        while stride < chunks {
+            let mut i = 0;
 
-            while let Some(element) = iter.next() {
-                output = output + *element;
+            while i + stride < chunks {
+                vec.set_chunk(i, vec.chunk(i) + vec.chunk(i + stride));
+                i += stride * 2;
             }
 
-            output
+            stride *= 2;
         }
+
+        let mut output = if chunks > 0 {
+            vec.chunk(0).sum_unordered()
+        } else {
+            <T as Zero>::zero()
+        };
+
+        let mut iter = vec.array[chunks * Vec::<T, N>::LANES..].iter();
+
+        while let Some(element) = iter.next() {
+            output = output + *element;
+        }
+
+        output
     }
 
-    unsafe { const_eval_select((vec,), scalar_sum, simd_sum) }
+    unsafe { const_eval_select!((vec,), scalar_sum, simd_sum_unordered) }
 }
@@ -1,7 +1,13 @@
 use crate::identity::{One, Zero};
-use crate::{Element, LaneCount, Lanes, Real, Vec};
+use crate::{ApproxEq, Cast, Element, LaneCount, Lanes, ParseError, Real, Vec, Vec3};
+use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 use core::ptr;
+use core::str::FromStr;
+
+mod swizzle;
 
 /// Specialization of `Vec<T, 2>`.
 #[derive(Clone, Copy, Debug)]
@@ -144,6 +150,29 @@ impl<T> Vec2<T> {
         Vec::from_array(*self.as_array())
     }
 
+    /// Converts each lane to `U`, with the same semantics as `as`.
+    ///
+    /// Float to integer conversions saturate, and NaN converts to `0`.
+    #[inline]
+    #[must_use]
+    pub const fn cast<U>(self) -> Vec2<U>
+    where
+        T: ~const Element,
+        T: ~const Cast<U>,
+        U: ~const Element,
+        Lanes<T, 2>: LaneCount,
+        [(); <Lanes<T, 2> as LaneCount>::LANES]:,
+    {
+        Vec2::from_vec(self.to_vec().cast())
+    }
+
+    /// Creates a new `Vec3<T>` with `z` appended.
+    #[inline]
+    #[must_use]
+    pub const fn extend(self, z: T) -> Vec3<T> {
+        Vec3::from_xyz(self.x, self.y, z)
+    }
+
     #[inline]
     pub const fn distance(self, other: Vec2<T>) -> T
     where
@@ -269,6 +298,38 @@ impl<T> Vec2<T> {
     }
 }
 
+impl Vec2<f32> {
+    /// Raw transmutation of each lane to its bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> Vec2<u32> {
+        Vec2::from_vec(self.to_vec().to_bits())
+    }
+
+    /// Raw transmutation of each lane from its bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: Vec2<u32>) -> Vec2<f32> {
+        Vec2::from_vec(Vec::from_bits(bits.to_vec()))
+    }
+}
+
+impl Vec2<f64> {
+    /// Raw transmutation of each lane to its bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> Vec2<u64> {
+        Vec2::from_vec(self.to_vec().to_bits())
+    }
+
+    /// Raw transmutation of each lane from its bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: Vec2<u64>) -> Vec2<f64> {
+        Vec2::from_vec(Vec::from_bits(bits.to_vec()))
+    }
+}
+
 impl<T> const Add for Vec2<T>
 where
     T: ~const Element,
@@ -413,3 +474,148 @@ where
         *self = *self - other;
     }
 }
+
+impl<T> const PartialEq for Vec2<T>
+where
+    T: ~const Element,
+    T: ~const PartialEq,
+    Lanes<T, 2>: LaneCount,
+    [(); <Lanes<T, 2> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn eq(&self, other: &Vec2<T>) -> bool {
+        self.to_vec() == other.to_vec()
+    }
+}
+
+impl<T> Eq for Vec2<T>
+where
+    T: Element,
+    T: Eq,
+    Lanes<T, 2>: LaneCount,
+    [(); <Lanes<T, 2> as LaneCount>::LANES]:,
+{
+}
+
+/// Lexicographic ordering, same as arrays.
+impl<T> const PartialOrd for Vec2<T>
+where
+    T: ~const Element,
+    T: ~const Ord,
+    Lanes<T, 2>: LaneCount,
+    [(); <Lanes<T, 2> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Vec2<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexicographic ordering, same as arrays.
+impl<T> const Ord for Vec2<T>
+where
+    T: ~const Element,
+    T: ~const Ord,
+    Lanes<T, 2>: LaneCount,
+    [(); <Lanes<T, 2> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn cmp(&self, other: &Vec2<T>) -> Ordering {
+        self.to_vec().cmp(&other.to_vec())
+    }
+}
+
+impl<T> Hash for Vec2<T>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_array().hash(state);
+    }
+}
+
+impl<T> const Default for Vec2<T>
+where
+    T: Copy,
+    T: ~const Zero,
+{
+    /// Creates a new vector with all elements set to zero.
+    #[inline]
+    fn default() -> Vec2<T> {
+        Self::zero()
+    }
+}
+
+/// Lane-wise approximate equality, same as `Vec`.
+impl<T> const ApproxEq for Vec2<T>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: ~const ApproxEq<Epsilon = T>,
+    T: ~const PartialOrd,
+    T: ~const Sub<Output = T>,
+    T: ~const Mul<Output = T>,
+    Lanes<T, 2>: LaneCount,
+    [(); <Lanes<T, 2> as LaneCount>::LANES]:,
+{
+    type Epsilon = T;
+
+    const DEFAULT_EPSILON: T = <T as ApproxEq>::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T = <T as ApproxEq>::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = <T as ApproxEq>::DEFAULT_MAX_ULPS;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Vec2<T>, epsilon: T) -> bool {
+        self.to_vec().abs_diff_eq(&other.to_vec(), epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Vec2<T>, epsilon: T, max_relative: T) -> bool {
+        self.to_vec().relative_eq(&other.to_vec(), epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Vec2<T>, epsilon: T, max_ulps: u32) -> bool {
+        self.to_vec().ulps_eq(&other.to_vec(), epsilon, max_ulps)
+    }
+}
+
+/// Formats as `(1, 2)`, or `(x: 1, y: 2)` for `{:#}`.
+///
+/// Width, and precision apply to each lane.
+///
+/// # Examples
+///
+/// ```
+/// use meth::Vec2;
+///
+/// let vec = Vec2::from_xy(1.0, 2.5);
+///
+/// assert_eq!(format!("{vec:.2}"), "(1.00, 2.50)");
+/// assert_eq!(format!("{vec:#.2}"), "(x: 1.00, y: 2.50)");
+/// ```
+impl<T> fmt::Display for Vec2<T>
+where
+    T: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::fields(fmt, &["x", "y"], &[&self.x, &self.y])
+    }
+}
+
+/// Parses lanes the same as `Vec`, such as `1 2`, `(1, 2)`, or `[1,2]`.
+impl<T> FromStr for Vec2<T>
+where
+    T: FromStr,
+{
+    type Err = ParseError<T::Err>;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Vec2<T>, ParseError<T::Err>> {
+        let [x, y] = crate::parse::parse_array(string, None)?;
+
+        Ok(Self::from_xy(x, y))
+    }
+}
@@ -0,0 +1,71 @@
+use super::Vec2;
+use crate::{Element, Vec3, Vec4};
+
+macro_rules! impl_swizzle {
+    { $($fn:ident => $output:ident<$m:literal> [$($index:literal),*];)* } => {
+        impl<T> Vec2<T> {
+            $(
+                #[inline]
+                #[must_use]
+                pub const fn $fn(self) -> $output<T>
+                where
+                    T: ~const Element,
+                {
+                    $output::from_vec(self.to_vec().shuffle::<$m, { [$($index),*] }>())
+                }
+            )*
+        }
+    }
+}
+
+macro_rules! impl_swizzle_set {
+    { $($fn:ident => $input:ident { $($dst:ident = $src:ident),* };)* } => {
+        impl<T> Vec2<T> {
+            $(
+                #[inline]
+                pub const fn $fn(&mut self, value: $input<T>)
+                where
+                    T: Copy,
+                {
+                    $(self.$dst = value.$src;)*
+                }
+            )*
+        }
+    }
+}
+
+impl_swizzle! {
+    xx => Vec2<2> [0, 0];
+    xy => Vec2<2> [0, 1];
+    yx => Vec2<2> [1, 0];
+    yy => Vec2<2> [1, 1];
+    xxx => Vec3<3> [0, 0, 0];
+    xxy => Vec3<3> [0, 0, 1];
+    xyx => Vec3<3> [0, 1, 0];
+    xyy => Vec3<3> [0, 1, 1];
+    yxx => Vec3<3> [1, 0, 0];
+    yxy => Vec3<3> [1, 0, 1];
+    yyx => Vec3<3> [1, 1, 0];
+    yyy => Vec3<3> [1, 1, 1];
+    xxxx => Vec4<4> [0, 0, 0, 0];
+    xxxy => Vec4<4> [0, 0, 0, 1];
+    xxyx => Vec4<4> [0, 0, 1, 0];
+    xxyy => Vec4<4> [0, 0, 1, 1];
+    xyxx => Vec4<4> [0, 1, 0, 0];
+    xyxy => Vec4<4> [0, 1, 0, 1];
+    xyyx => Vec4<4> [0, 1, 1, 0];
+    xyyy => Vec4<4> [0, 1, 1, 1];
+    yxxx => Vec4<4> [1, 0, 0, 0];
+    yxxy => Vec4<4> [1, 0, 0, 1];
+    yxyx => Vec4<4> [1, 0, 1, 0];
+    yxyy => Vec4<4> [1, 0, 1, 1];
+    yyxx => Vec4<4> [1, 1, 0, 0];
+    yyxy => Vec4<4> [1, 1, 0, 1];
+    yyyx => Vec4<4> [1, 1, 1, 0];
+    yyyy => Vec4<4> [1, 1, 1, 1];
+}
+
+impl_swizzle_set! {
+    set_xy => Vec2 { x = x, y = y };
+    set_yx => Vec2 { y = x, x = y };
+}
@@ -0,0 +1,170 @@
+use super::Vec3;
+use crate::{Element, Vec2, Vec4};
+
+macro_rules! impl_swizzle {
+    { $($fn:ident => $output:ident<$m:literal> [$($index:literal),*];)* } => {
+        impl<T> Vec3<T> {
+            $(
+                #[inline]
+                #[must_use]
+                pub const fn $fn(self) -> $output<T>
+                where
+                    T: ~const Element,
+                {
+                    $output::from_vec(self.to_vec().shuffle::<$m, { [$($index),*] }>())
+                }
+            )*
+        }
+    }
+}
+
+macro_rules! impl_swizzle_set {
+    { $($fn:ident => $input:ident { $($dst:ident = $src:ident),* };)* } => {
+        impl<T> Vec3<T> {
+            $(
+                #[inline]
+                pub const fn $fn(&mut self, value: $input<T>)
+                where
+                    T: Copy,
+                {
+                    $(self.$dst = value.$src;)*
+                }
+            )*
+        }
+    }
+}
+
+impl_swizzle! {
+    xx => Vec2<2> [0, 0];
+    xy => Vec2<2> [0, 1];
+    xz => Vec2<2> [0, 2];
+    yx => Vec2<2> [1, 0];
+    yy => Vec2<2> [1, 1];
+    yz => Vec2<2> [1, 2];
+    zx => Vec2<2> [2, 0];
+    zy => Vec2<2> [2, 1];
+    zz => Vec2<2> [2, 2];
+    xxx => Vec3<3> [0, 0, 0];
+    xxy => Vec3<3> [0, 0, 1];
+    xxz => Vec3<3> [0, 0, 2];
+    xyx => Vec3<3> [0, 1, 0];
+    xyy => Vec3<3> [0, 1, 1];
+    xyz => Vec3<3> [0, 1, 2];
+    xzx => Vec3<3> [0, 2, 0];
+    xzy => Vec3<3> [0, 2, 1];
+    xzz => Vec3<3> [0, 2, 2];
+    yxx => Vec3<3> [1, 0, 0];
+    yxy => Vec3<3> [1, 0, 1];
+    yxz => Vec3<3> [1, 0, 2];
+    yyx => Vec3<3> [1, 1, 0];
+    yyy => Vec3<3> [1, 1, 1];
+    yyz => Vec3<3> [1, 1, 2];
+    yzx => Vec3<3> [1, 2, 0];
+    yzy => Vec3<3> [1, 2, 1];
+    yzz => Vec3<3> [1, 2, 2];
+    zxx => Vec3<3> [2, 0, 0];
+    zxy => Vec3<3> [2, 0, 1];
+    zxz => Vec3<3> [2, 0, 2];
+    zyx => Vec3<3> [2, 1, 0];
+    zyy => Vec3<3> [2, 1, 1];
+    zyz => Vec3<3> [2, 1, 2];
+    zzx => Vec3<3> [2, 2, 0];
+    zzy => Vec3<3> [2, 2, 1];
+    zzz => Vec3<3> [2, 2, 2];
+    xxxx => Vec4<4> [0, 0, 0, 0];
+    xxxy => Vec4<4> [0, 0, 0, 1];
+    xxxz => Vec4<4> [0, 0, 0, 2];
+    xxyx => Vec4<4> [0, 0, 1, 0];
+    xxyy => Vec4<4> [0, 0, 1, 1];
+    xxyz => Vec4<4> [0, 0, 1, 2];
+    xxzx => Vec4<4> [0, 0, 2, 0];
+    xxzy => Vec4<4> [0, 0, 2, 1];
+    xxzz => Vec4<4> [0, 0, 2, 2];
+    xyxx => Vec4<4> [0, 1, 0, 0];
+    xyxy => Vec4<4> [0, 1, 0, 1];
+    xyxz => Vec4<4> [0, 1, 0, 2];
+    xyyx => Vec4<4> [0, 1, 1, 0];
+    xyyy => Vec4<4> [0, 1, 1, 1];
+    xyyz => Vec4<4> [0, 1, 1, 2];
+    xyzx => Vec4<4> [0, 1, 2, 0];
+    xyzy => Vec4<4> [0, 1, 2, 1];
+    xyzz => Vec4<4> [0, 1, 2, 2];
+    xzxx => Vec4<4> [0, 2, 0, 0];
+    xzxy => Vec4<4> [0, 2, 0, 1];
+    xzxz => Vec4<4> [0, 2, 0, 2];
+    xzyx => Vec4<4> [0, 2, 1, 0];
+    xzyy => Vec4<4> [0, 2, 1, 1];
+    xzyz => Vec4<4> [0, 2, 1, 2];
+    xzzx => Vec4<4> [0, 2, 2, 0];
+    xzzy => Vec4<4> [0, 2, 2, 1];
+    xzzz => Vec4<4> [0, 2, 2, 2];
+    yxxx => Vec4<4> [1, 0, 0, 0];
+    yxxy => Vec4<4> [1, 0, 0, 1];
+    yxxz => Vec4<4> [1, 0, 0, 2];
+    yxyx => Vec4<4> [1, 0, 1, 0];
+    yxyy => Vec4<4> [1, 0, 1, 1];
+    yxyz => Vec4<4> [1, 0, 1, 2];
+    yxzx => Vec4<4> [1, 0, 2, 0];
+    yxzy => Vec4<4> [1, 0, 2, 1];
+    yxzz => Vec4<4> [1, 0, 2, 2];
+    yyxx => Vec4<4> [1, 1, 0, 0];
+    yyxy => Vec4<4> [1, 1, 0, 1];
+    yyxz => Vec4<4> [1, 1, 0, 2];
+    yyyx => Vec4<4> [1, 1, 1, 0];
+    yyyy => Vec4<4> [1, 1, 1, 1];
+    yyyz => Vec4<4> [1, 1, 1, 2];
+    yyzx => Vec4<4> [1, 1, 2, 0];
+    yyzy => Vec4<4> [1, 1, 2, 1];
+    yyzz => Vec4<4> [1, 1, 2, 2];
+    yzxx => Vec4<4> [1, 2, 0, 0];
+    yzxy => Vec4<4> [1, 2, 0, 1];
+    yzxz => Vec4<4> [1, 2, 0, 2];
+    yzyx => Vec4<4> [1, 2, 1, 0];
+    yzyy => Vec4<4> [1, 2, 1, 1];
+    yzyz => Vec4<4> [1, 2, 1, 2];
+    yzzx => Vec4<4> [1, 2, 2, 0];
+    yzzy => Vec4<4> [1, 2, 2, 1];
+    yzzz => Vec4<4> [1, 2, 2, 2];
+    zxxx => Vec4<4> [2, 0, 0, 0];
+    zxxy => Vec4<4> [2, 0, 0, 1];
+    zxxz => Vec4<4> [2, 0, 0, 2];
+    zxyx => Vec4<4> [2, 0, 1, 0];
+    zxyy => Vec4<4> [2, 0, 1, 1];
+    zxyz => Vec4<4> [2, 0, 1, 2];
+    zxzx => Vec4<4> [2, 0, 2, 0];
+    zxzy => Vec4<4> [2, 0, 2, 1];
+    zxzz => Vec4<4> [2, 0, 2, 2];
+    zyxx => Vec4<4> [2, 1, 0, 0];
+    zyxy => Vec4<4> [2, 1, 0, 1];
+    zyxz => Vec4<4> [2, 1, 0, 2];
+    zyyx => Vec4<4> [2, 1, 1, 0];
+    zyyy => Vec4<4> [2, 1, 1, 1];
+    zyyz => Vec4<4> [2, 1, 1, 2];
+    zyzx => Vec4<4> [2, 1, 2, 0];
+    zyzy => Vec4<4> [2, 1, 2, 1];
+    zyzz => Vec4<4> [2, 1, 2, 2];
+    zzxx => Vec4<4> [2, 2, 0, 0];
+    zzxy => Vec4<4> [2, 2, 0, 1];
+    zzxz => Vec4<4> [2, 2, 0, 2];
+    zzyx => Vec4<4> [2, 2, 1, 0];
+    zzyy => Vec4<4> [2, 2, 1, 1];
+    zzyz => Vec4<4> [2, 2, 1, 2];
+    zzzx => Vec4<4> [2, 2, 2, 0];
+    zzzy => Vec4<4> [2, 2, 2, 1];
+    zzzz => Vec4<4> [2, 2, 2, 2];
+}
+
+impl_swizzle_set! {
+    set_xy => Vec2 { x = x, y = y };
+    set_xz => Vec2 { x = x, z = y };
+    set_yx => Vec2 { y = x, x = y };
+    set_yz => Vec2 { y = x, z = y };
+    set_zx => Vec2 { z = x, x = y };
+    set_zy => Vec2 { z = x, y = y };
+    set_xyz => Vec3 { x = x, y = y, z = z };
+    set_xzy => Vec3 { x = x, z = y, y = z };
+    set_yxz => Vec3 { y = x, x = y, z = z };
+    set_yzx => Vec3 { y = x, z = y, x = z };
+    set_zxy => Vec3 { z = x, x = y, y = z };
+    set_zyx => Vec3 { z = x, y = y, x = z };
+}
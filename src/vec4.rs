@@ -1,7 +1,13 @@
 use crate::identity::{One, Zero};
-use crate::{Element, LaneCount, Lanes, Real, Vec};
+use crate::{ApproxEq, Cast, Element, LaneCount, Lanes, ParseError, Real, Vec, Vec3};
+use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 use core::ptr;
+use core::str::FromStr;
+
+mod swizzle;
 
 /// Specialization of `Vec<T, 4>`.
 #[derive(Clone, Copy, Debug)]
@@ -157,6 +163,32 @@ impl<T> Vec4<T> {
         Vec::from_array(*self.as_array())
     }
 
+    /// Converts each lane to `U`, with the same semantics as `as`.
+    ///
+    /// Float to integer conversions saturate, and NaN converts to `0`.
+    #[inline]
+    #[must_use]
+    pub const fn cast<U>(self) -> Vec4<U>
+    where
+        T: ~const Element,
+        T: ~const Cast<U>,
+        U: ~const Element,
+        Lanes<T, 4>: LaneCount,
+        [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+    {
+        Vec4::from_vec(self.to_vec().cast())
+    }
+
+    /// Creates a new `Vec3<T>` from x, y, and z coordinates, discarding w.
+    #[inline]
+    #[must_use]
+    pub const fn truncate(self) -> Vec3<T>
+    where
+        T: Copy,
+    {
+        Vec3::from_xyz(self.x, self.y, self.z)
+    }
+
     #[inline]
     pub const fn distance(self, other: Vec4<T>) -> T
     where
@@ -282,6 +314,38 @@ impl<T> Vec4<T> {
     }
 }
 
+impl Vec4<f32> {
+    /// Raw transmutation of each lane to its bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> Vec4<u32> {
+        Vec4::from_vec(self.to_vec().to_bits())
+    }
+
+    /// Raw transmutation of each lane from its bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: Vec4<u32>) -> Vec4<f32> {
+        Vec4::from_vec(Vec::from_bits(bits.to_vec()))
+    }
+}
+
+impl Vec4<f64> {
+    /// Raw transmutation of each lane to its bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> Vec4<u64> {
+        Vec4::from_vec(self.to_vec().to_bits())
+    }
+
+    /// Raw transmutation of each lane from its bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: Vec4<u64>) -> Vec4<f64> {
+        Vec4::from_vec(Vec::from_bits(bits.to_vec()))
+    }
+}
+
 impl<T> const Add for Vec4<T>
 where
     T: ~const Element,
@@ -426,3 +490,148 @@ where
         *self = *self - other;
     }
 }
+
+impl<T> const PartialEq for Vec4<T>
+where
+    T: ~const Element,
+    T: ~const PartialEq,
+    Lanes<T, 4>: LaneCount,
+    [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn eq(&self, other: &Vec4<T>) -> bool {
+        self.to_vec() == other.to_vec()
+    }
+}
+
+impl<T> Eq for Vec4<T>
+where
+    T: Element,
+    T: Eq,
+    Lanes<T, 4>: LaneCount,
+    [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+{
+}
+
+/// Lexicographic ordering, same as arrays.
+impl<T> const PartialOrd for Vec4<T>
+where
+    T: ~const Element,
+    T: ~const Ord,
+    Lanes<T, 4>: LaneCount,
+    [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Vec4<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexicographic ordering, same as arrays.
+impl<T> const Ord for Vec4<T>
+where
+    T: ~const Element,
+    T: ~const Ord,
+    Lanes<T, 4>: LaneCount,
+    [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+{
+    #[inline]
+    fn cmp(&self, other: &Vec4<T>) -> Ordering {
+        self.to_vec().cmp(&other.to_vec())
+    }
+}
+
+impl<T> Hash for Vec4<T>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_array().hash(state);
+    }
+}
+
+impl<T> const Default for Vec4<T>
+where
+    T: Copy,
+    T: ~const Zero,
+{
+    /// Creates a new vector with all elements set to zero.
+    #[inline]
+    fn default() -> Vec4<T> {
+        Self::zero()
+    }
+}
+
+/// Lane-wise approximate equality, same as `Vec`.
+impl<T> const ApproxEq for Vec4<T>
+where
+    T: ~const Element,
+    T: ~const Real,
+    T: ~const ApproxEq<Epsilon = T>,
+    T: ~const PartialOrd,
+    T: ~const Sub<Output = T>,
+    T: ~const Mul<Output = T>,
+    Lanes<T, 4>: LaneCount,
+    [(); <Lanes<T, 4> as LaneCount>::LANES]:,
+{
+    type Epsilon = T;
+
+    const DEFAULT_EPSILON: T = <T as ApproxEq>::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T = <T as ApproxEq>::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = <T as ApproxEq>::DEFAULT_MAX_ULPS;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Vec4<T>, epsilon: T) -> bool {
+        self.to_vec().abs_diff_eq(&other.to_vec(), epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Vec4<T>, epsilon: T, max_relative: T) -> bool {
+        self.to_vec().relative_eq(&other.to_vec(), epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Vec4<T>, epsilon: T, max_ulps: u32) -> bool {
+        self.to_vec().ulps_eq(&other.to_vec(), epsilon, max_ulps)
+    }
+}
+
+/// Formats as `(1, 2, 3, 4)`, or `(x: 1, y: 2, z: 3, w: 4)` for `{:#}`.
+///
+/// Width, and precision apply to each lane.
+///
+/// # Examples
+///
+/// ```
+/// use meth::Vec4;
+///
+/// let vec = Vec4::from_xyzw(1.0, 2.5, -3.0, 0.0);
+///
+/// assert_eq!(format!("{vec:.2}"), "(1.00, 2.50, -3.00, 0.00)");
+/// assert_eq!(format!("{vec:#.2}"), "(x: 1.00, y: 2.50, z: -3.00, w: 0.00)");
+/// ```
+impl<T> fmt::Display for Vec4<T>
+where
+    T: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::fields(fmt, &["x", "y", "z", "w"], &[&self.x, &self.y, &self.z, &self.w])
+    }
+}
+
+/// Parses lanes the same as `Vec`, such as `1 2 3 4`, `(1, 2, 3, 4)`, or `[1,2,3,4]`.
+impl<T> FromStr for Vec4<T>
+where
+    T: FromStr,
+{
+    type Err = ParseError<T::Err>;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Vec4<T>, ParseError<T::Err>> {
+        let [x, y, z, w] = crate::parse::parse_array(string, None)?;
+
+        Ok(Self::from_xyzw(x, y, z, w))
+    }
+}
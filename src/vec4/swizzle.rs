@@ -0,0 +1,437 @@
+use super::Vec4;
+use crate::{Element, Vec2, Vec3};
+
+macro_rules! impl_swizzle {
+    { $($fn:ident => $output:ident<$m:literal> [$($index:literal),*];)* } => {
+        impl<T> Vec4<T> {
+            $(
+                #[inline]
+                #[must_use]
+                pub const fn $fn(self) -> $output<T>
+                where
+                    T: ~const Element,
+                {
+                    $output::from_vec(self.to_vec().shuffle::<$m, { [$($index),*] }>())
+                }
+            )*
+        }
+    }
+}
+
+macro_rules! impl_swizzle_set {
+    { $($fn:ident => $input:ident { $($dst:ident = $src:ident),* };)* } => {
+        impl<T> Vec4<T> {
+            $(
+                #[inline]
+                pub const fn $fn(&mut self, value: $input<T>)
+                where
+                    T: Copy,
+                {
+                    $(self.$dst = value.$src;)*
+                }
+            )*
+        }
+    }
+}
+
+impl_swizzle! {
+    xx => Vec2<2> [0, 0];
+    xy => Vec2<2> [0, 1];
+    xz => Vec2<2> [0, 2];
+    xw => Vec2<2> [0, 3];
+    yx => Vec2<2> [1, 0];
+    yy => Vec2<2> [1, 1];
+    yz => Vec2<2> [1, 2];
+    yw => Vec2<2> [1, 3];
+    zx => Vec2<2> [2, 0];
+    zy => Vec2<2> [2, 1];
+    zz => Vec2<2> [2, 2];
+    zw => Vec2<2> [2, 3];
+    wx => Vec2<2> [3, 0];
+    wy => Vec2<2> [3, 1];
+    wz => Vec2<2> [3, 2];
+    ww => Vec2<2> [3, 3];
+    xxx => Vec3<3> [0, 0, 0];
+    xxy => Vec3<3> [0, 0, 1];
+    xxz => Vec3<3> [0, 0, 2];
+    xxw => Vec3<3> [0, 0, 3];
+    xyx => Vec3<3> [0, 1, 0];
+    xyy => Vec3<3> [0, 1, 1];
+    xyz => Vec3<3> [0, 1, 2];
+    xyw => Vec3<3> [0, 1, 3];
+    xzx => Vec3<3> [0, 2, 0];
+    xzy => Vec3<3> [0, 2, 1];
+    xzz => Vec3<3> [0, 2, 2];
+    xzw => Vec3<3> [0, 2, 3];
+    xwx => Vec3<3> [0, 3, 0];
+    xwy => Vec3<3> [0, 3, 1];
+    xwz => Vec3<3> [0, 3, 2];
+    xww => Vec3<3> [0, 3, 3];
+    yxx => Vec3<3> [1, 0, 0];
+    yxy => Vec3<3> [1, 0, 1];
+    yxz => Vec3<3> [1, 0, 2];
+    yxw => Vec3<3> [1, 0, 3];
+    yyx => Vec3<3> [1, 1, 0];
+    yyy => Vec3<3> [1, 1, 1];
+    yyz => Vec3<3> [1, 1, 2];
+    yyw => Vec3<3> [1, 1, 3];
+    yzx => Vec3<3> [1, 2, 0];
+    yzy => Vec3<3> [1, 2, 1];
+    yzz => Vec3<3> [1, 2, 2];
+    yzw => Vec3<3> [1, 2, 3];
+    ywx => Vec3<3> [1, 3, 0];
+    ywy => Vec3<3> [1, 3, 1];
+    ywz => Vec3<3> [1, 3, 2];
+    yww => Vec3<3> [1, 3, 3];
+    zxx => Vec3<3> [2, 0, 0];
+    zxy => Vec3<3> [2, 0, 1];
+    zxz => Vec3<3> [2, 0, 2];
+    zxw => Vec3<3> [2, 0, 3];
+    zyx => Vec3<3> [2, 1, 0];
+    zyy => Vec3<3> [2, 1, 1];
+    zyz => Vec3<3> [2, 1, 2];
+    zyw => Vec3<3> [2, 1, 3];
+    zzx => Vec3<3> [2, 2, 0];
+    zzy => Vec3<3> [2, 2, 1];
+    zzz => Vec3<3> [2, 2, 2];
+    zzw => Vec3<3> [2, 2, 3];
+    zwx => Vec3<3> [2, 3, 0];
+    zwy => Vec3<3> [2, 3, 1];
+    zwz => Vec3<3> [2, 3, 2];
+    zww => Vec3<3> [2, 3, 3];
+    wxx => Vec3<3> [3, 0, 0];
+    wxy => Vec3<3> [3, 0, 1];
+    wxz => Vec3<3> [3, 0, 2];
+    wxw => Vec3<3> [3, 0, 3];
+    wyx => Vec3<3> [3, 1, 0];
+    wyy => Vec3<3> [3, 1, 1];
+    wyz => Vec3<3> [3, 1, 2];
+    wyw => Vec3<3> [3, 1, 3];
+    wzx => Vec3<3> [3, 2, 0];
+    wzy => Vec3<3> [3, 2, 1];
+    wzz => Vec3<3> [3, 2, 2];
+    wzw => Vec3<3> [3, 2, 3];
+    wwx => Vec3<3> [3, 3, 0];
+    wwy => Vec3<3> [3, 3, 1];
+    wwz => Vec3<3> [3, 3, 2];
+    www => Vec3<3> [3, 3, 3];
+    xxxx => Vec4<4> [0, 0, 0, 0];
+    xxxy => Vec4<4> [0, 0, 0, 1];
+    xxxz => Vec4<4> [0, 0, 0, 2];
+    xxxw => Vec4<4> [0, 0, 0, 3];
+    xxyx => Vec4<4> [0, 0, 1, 0];
+    xxyy => Vec4<4> [0, 0, 1, 1];
+    xxyz => Vec4<4> [0, 0, 1, 2];
+    xxyw => Vec4<4> [0, 0, 1, 3];
+    xxzx => Vec4<4> [0, 0, 2, 0];
+    xxzy => Vec4<4> [0, 0, 2, 1];
+    xxzz => Vec4<4> [0, 0, 2, 2];
+    xxzw => Vec4<4> [0, 0, 2, 3];
+    xxwx => Vec4<4> [0, 0, 3, 0];
+    xxwy => Vec4<4> [0, 0, 3, 1];
+    xxwz => Vec4<4> [0, 0, 3, 2];
+    xxww => Vec4<4> [0, 0, 3, 3];
+    xyxx => Vec4<4> [0, 1, 0, 0];
+    xyxy => Vec4<4> [0, 1, 0, 1];
+    xyxz => Vec4<4> [0, 1, 0, 2];
+    xyxw => Vec4<4> [0, 1, 0, 3];
+    xyyx => Vec4<4> [0, 1, 1, 0];
+    xyyy => Vec4<4> [0, 1, 1, 1];
+    xyyz => Vec4<4> [0, 1, 1, 2];
+    xyyw => Vec4<4> [0, 1, 1, 3];
+    xyzx => Vec4<4> [0, 1, 2, 0];
+    xyzy => Vec4<4> [0, 1, 2, 1];
+    xyzz => Vec4<4> [0, 1, 2, 2];
+    xyzw => Vec4<4> [0, 1, 2, 3];
+    xywx => Vec4<4> [0, 1, 3, 0];
+    xywy => Vec4<4> [0, 1, 3, 1];
+    xywz => Vec4<4> [0, 1, 3, 2];
+    xyww => Vec4<4> [0, 1, 3, 3];
+    xzxx => Vec4<4> [0, 2, 0, 0];
+    xzxy => Vec4<4> [0, 2, 0, 1];
+    xzxz => Vec4<4> [0, 2, 0, 2];
+    xzxw => Vec4<4> [0, 2, 0, 3];
+    xzyx => Vec4<4> [0, 2, 1, 0];
+    xzyy => Vec4<4> [0, 2, 1, 1];
+    xzyz => Vec4<4> [0, 2, 1, 2];
+    xzyw => Vec4<4> [0, 2, 1, 3];
+    xzzx => Vec4<4> [0, 2, 2, 0];
+    xzzy => Vec4<4> [0, 2, 2, 1];
+    xzzz => Vec4<4> [0, 2, 2, 2];
+    xzzw => Vec4<4> [0, 2, 2, 3];
+    xzwx => Vec4<4> [0, 2, 3, 0];
+    xzwy => Vec4<4> [0, 2, 3, 1];
+    xzwz => Vec4<4> [0, 2, 3, 2];
+    xzww => Vec4<4> [0, 2, 3, 3];
+    xwxx => Vec4<4> [0, 3, 0, 0];
+    xwxy => Vec4<4> [0, 3, 0, 1];
+    xwxz => Vec4<4> [0, 3, 0, 2];
+    xwxw => Vec4<4> [0, 3, 0, 3];
+    xwyx => Vec4<4> [0, 3, 1, 0];
+    xwyy => Vec4<4> [0, 3, 1, 1];
+    xwyz => Vec4<4> [0, 3, 1, 2];
+    xwyw => Vec4<4> [0, 3, 1, 3];
+    xwzx => Vec4<4> [0, 3, 2, 0];
+    xwzy => Vec4<4> [0, 3, 2, 1];
+    xwzz => Vec4<4> [0, 3, 2, 2];
+    xwzw => Vec4<4> [0, 3, 2, 3];
+    xwwx => Vec4<4> [0, 3, 3, 0];
+    xwwy => Vec4<4> [0, 3, 3, 1];
+    xwwz => Vec4<4> [0, 3, 3, 2];
+    xwww => Vec4<4> [0, 3, 3, 3];
+    yxxx => Vec4<4> [1, 0, 0, 0];
+    yxxy => Vec4<4> [1, 0, 0, 1];
+    yxxz => Vec4<4> [1, 0, 0, 2];
+    yxxw => Vec4<4> [1, 0, 0, 3];
+    yxyx => Vec4<4> [1, 0, 1, 0];
+    yxyy => Vec4<4> [1, 0, 1, 1];
+    yxyz => Vec4<4> [1, 0, 1, 2];
+    yxyw => Vec4<4> [1, 0, 1, 3];
+    yxzx => Vec4<4> [1, 0, 2, 0];
+    yxzy => Vec4<4> [1, 0, 2, 1];
+    yxzz => Vec4<4> [1, 0, 2, 2];
+    yxzw => Vec4<4> [1, 0, 2, 3];
+    yxwx => Vec4<4> [1, 0, 3, 0];
+    yxwy => Vec4<4> [1, 0, 3, 1];
+    yxwz => Vec4<4> [1, 0, 3, 2];
+    yxww => Vec4<4> [1, 0, 3, 3];
+    yyxx => Vec4<4> [1, 1, 0, 0];
+    yyxy => Vec4<4> [1, 1, 0, 1];
+    yyxz => Vec4<4> [1, 1, 0, 2];
+    yyxw => Vec4<4> [1, 1, 0, 3];
+    yyyx => Vec4<4> [1, 1, 1, 0];
+    yyyy => Vec4<4> [1, 1, 1, 1];
+    yyyz => Vec4<4> [1, 1, 1, 2];
+    yyyw => Vec4<4> [1, 1, 1, 3];
+    yyzx => Vec4<4> [1, 1, 2, 0];
+    yyzy => Vec4<4> [1, 1, 2, 1];
+    yyzz => Vec4<4> [1, 1, 2, 2];
+    yyzw => Vec4<4> [1, 1, 2, 3];
+    yywx => Vec4<4> [1, 1, 3, 0];
+    yywy => Vec4<4> [1, 1, 3, 1];
+    yywz => Vec4<4> [1, 1, 3, 2];
+    yyww => Vec4<4> [1, 1, 3, 3];
+    yzxx => Vec4<4> [1, 2, 0, 0];
+    yzxy => Vec4<4> [1, 2, 0, 1];
+    yzxz => Vec4<4> [1, 2, 0, 2];
+    yzxw => Vec4<4> [1, 2, 0, 3];
+    yzyx => Vec4<4> [1, 2, 1, 0];
+    yzyy => Vec4<4> [1, 2, 1, 1];
+    yzyz => Vec4<4> [1, 2, 1, 2];
+    yzyw => Vec4<4> [1, 2, 1, 3];
+    yzzx => Vec4<4> [1, 2, 2, 0];
+    yzzy => Vec4<4> [1, 2, 2, 1];
+    yzzz => Vec4<4> [1, 2, 2, 2];
+    yzzw => Vec4<4> [1, 2, 2, 3];
+    yzwx => Vec4<4> [1, 2, 3, 0];
+    yzwy => Vec4<4> [1, 2, 3, 1];
+    yzwz => Vec4<4> [1, 2, 3, 2];
+    yzww => Vec4<4> [1, 2, 3, 3];
+    ywxx => Vec4<4> [1, 3, 0, 0];
+    ywxy => Vec4<4> [1, 3, 0, 1];
+    ywxz => Vec4<4> [1, 3, 0, 2];
+    ywxw => Vec4<4> [1, 3, 0, 3];
+    ywyx => Vec4<4> [1, 3, 1, 0];
+    ywyy => Vec4<4> [1, 3, 1, 1];
+    ywyz => Vec4<4> [1, 3, 1, 2];
+    ywyw => Vec4<4> [1, 3, 1, 3];
+    ywzx => Vec4<4> [1, 3, 2, 0];
+    ywzy => Vec4<4> [1, 3, 2, 1];
+    ywzz => Vec4<4> [1, 3, 2, 2];
+    ywzw => Vec4<4> [1, 3, 2, 3];
+    ywwx => Vec4<4> [1, 3, 3, 0];
+    ywwy => Vec4<4> [1, 3, 3, 1];
+    ywwz => Vec4<4> [1, 3, 3, 2];
+    ywww => Vec4<4> [1, 3, 3, 3];
+    zxxx => Vec4<4> [2, 0, 0, 0];
+    zxxy => Vec4<4> [2, 0, 0, 1];
+    zxxz => Vec4<4> [2, 0, 0, 2];
+    zxxw => Vec4<4> [2, 0, 0, 3];
+    zxyx => Vec4<4> [2, 0, 1, 0];
+    zxyy => Vec4<4> [2, 0, 1, 1];
+    zxyz => Vec4<4> [2, 0, 1, 2];
+    zxyw => Vec4<4> [2, 0, 1, 3];
+    zxzx => Vec4<4> [2, 0, 2, 0];
+    zxzy => Vec4<4> [2, 0, 2, 1];
+    zxzz => Vec4<4> [2, 0, 2, 2];
+    zxzw => Vec4<4> [2, 0, 2, 3];
+    zxwx => Vec4<4> [2, 0, 3, 0];
+    zxwy => Vec4<4> [2, 0, 3, 1];
+    zxwz => Vec4<4> [2, 0, 3, 2];
+    zxww => Vec4<4> [2, 0, 3, 3];
+    zyxx => Vec4<4> [2, 1, 0, 0];
+    zyxy => Vec4<4> [2, 1, 0, 1];
+    zyxz => Vec4<4> [2, 1, 0, 2];
+    zyxw => Vec4<4> [2, 1, 0, 3];
+    zyyx => Vec4<4> [2, 1, 1, 0];
+    zyyy => Vec4<4> [2, 1, 1, 1];
+    zyyz => Vec4<4> [2, 1, 1, 2];
+    zyyw => Vec4<4> [2, 1, 1, 3];
+    zyzx => Vec4<4> [2, 1, 2, 0];
+    zyzy => Vec4<4> [2, 1, 2, 1];
+    zyzz => Vec4<4> [2, 1, 2, 2];
+    zyzw => Vec4<4> [2, 1, 2, 3];
+    zywx => Vec4<4> [2, 1, 3, 0];
+    zywy => Vec4<4> [2, 1, 3, 1];
+    zywz => Vec4<4> [2, 1, 3, 2];
+    zyww => Vec4<4> [2, 1, 3, 3];
+    zzxx => Vec4<4> [2, 2, 0, 0];
+    zzxy => Vec4<4> [2, 2, 0, 1];
+    zzxz => Vec4<4> [2, 2, 0, 2];
+    zzxw => Vec4<4> [2, 2, 0, 3];
+    zzyx => Vec4<4> [2, 2, 1, 0];
+    zzyy => Vec4<4> [2, 2, 1, 1];
+    zzyz => Vec4<4> [2, 2, 1, 2];
+    zzyw => Vec4<4> [2, 2, 1, 3];
+    zzzx => Vec4<4> [2, 2, 2, 0];
+    zzzy => Vec4<4> [2, 2, 2, 1];
+    zzzz => Vec4<4> [2, 2, 2, 2];
+    zzzw => Vec4<4> [2, 2, 2, 3];
+    zzwx => Vec4<4> [2, 2, 3, 0];
+    zzwy => Vec4<4> [2, 2, 3, 1];
+    zzwz => Vec4<4> [2, 2, 3, 2];
+    zzww => Vec4<4> [2, 2, 3, 3];
+    zwxx => Vec4<4> [2, 3, 0, 0];
+    zwxy => Vec4<4> [2, 3, 0, 1];
+    zwxz => Vec4<4> [2, 3, 0, 2];
+    zwxw => Vec4<4> [2, 3, 0, 3];
+    zwyx => Vec4<4> [2, 3, 1, 0];
+    zwyy => Vec4<4> [2, 3, 1, 1];
+    zwyz => Vec4<4> [2, 3, 1, 2];
+    zwyw => Vec4<4> [2, 3, 1, 3];
+    zwzx => Vec4<4> [2, 3, 2, 0];
+    zwzy => Vec4<4> [2, 3, 2, 1];
+    zwzz => Vec4<4> [2, 3, 2, 2];
+    zwzw => Vec4<4> [2, 3, 2, 3];
+    zwwx => Vec4<4> [2, 3, 3, 0];
+    zwwy => Vec4<4> [2, 3, 3, 1];
+    zwwz => Vec4<4> [2, 3, 3, 2];
+    zwww => Vec4<4> [2, 3, 3, 3];
+    wxxx => Vec4<4> [3, 0, 0, 0];
+    wxxy => Vec4<4> [3, 0, 0, 1];
+    wxxz => Vec4<4> [3, 0, 0, 2];
+    wxxw => Vec4<4> [3, 0, 0, 3];
+    wxyx => Vec4<4> [3, 0, 1, 0];
+    wxyy => Vec4<4> [3, 0, 1, 1];
+    wxyz => Vec4<4> [3, 0, 1, 2];
+    wxyw => Vec4<4> [3, 0, 1, 3];
+    wxzx => Vec4<4> [3, 0, 2, 0];
+    wxzy => Vec4<4> [3, 0, 2, 1];
+    wxzz => Vec4<4> [3, 0, 2, 2];
+    wxzw => Vec4<4> [3, 0, 2, 3];
+    wxwx => Vec4<4> [3, 0, 3, 0];
+    wxwy => Vec4<4> [3, 0, 3, 1];
+    wxwz => Vec4<4> [3, 0, 3, 2];
+    wxww => Vec4<4> [3, 0, 3, 3];
+    wyxx => Vec4<4> [3, 1, 0, 0];
+    wyxy => Vec4<4> [3, 1, 0, 1];
+    wyxz => Vec4<4> [3, 1, 0, 2];
+    wyxw => Vec4<4> [3, 1, 0, 3];
+    wyyx => Vec4<4> [3, 1, 1, 0];
+    wyyy => Vec4<4> [3, 1, 1, 1];
+    wyyz => Vec4<4> [3, 1, 1, 2];
+    wyyw => Vec4<4> [3, 1, 1, 3];
+    wyzx => Vec4<4> [3, 1, 2, 0];
+    wyzy => Vec4<4> [3, 1, 2, 1];
+    wyzz => Vec4<4> [3, 1, 2, 2];
+    wyzw => Vec4<4> [3, 1, 2, 3];
+    wywx => Vec4<4> [3, 1, 3, 0];
+    wywy => Vec4<4> [3, 1, 3, 1];
+    wywz => Vec4<4> [3, 1, 3, 2];
+    wyww => Vec4<4> [3, 1, 3, 3];
+    wzxx => Vec4<4> [3, 2, 0, 0];
+    wzxy => Vec4<4> [3, 2, 0, 1];
+    wzxz => Vec4<4> [3, 2, 0, 2];
+    wzxw => Vec4<4> [3, 2, 0, 3];
+    wzyx => Vec4<4> [3, 2, 1, 0];
+    wzyy => Vec4<4> [3, 2, 1, 1];
+    wzyz => Vec4<4> [3, 2, 1, 2];
+    wzyw => Vec4<4> [3, 2, 1, 3];
+    wzzx => Vec4<4> [3, 2, 2, 0];
+    wzzy => Vec4<4> [3, 2, 2, 1];
+    wzzz => Vec4<4> [3, 2, 2, 2];
+    wzzw => Vec4<4> [3, 2, 2, 3];
+    wzwx => Vec4<4> [3, 2, 3, 0];
+    wzwy => Vec4<4> [3, 2, 3, 1];
+    wzwz => Vec4<4> [3, 2, 3, 2];
+    wzww => Vec4<4> [3, 2, 3, 3];
+    wwxx => Vec4<4> [3, 3, 0, 0];
+    wwxy => Vec4<4> [3, 3, 0, 1];
+    wwxz => Vec4<4> [3, 3, 0, 2];
+    wwxw => Vec4<4> [3, 3, 0, 3];
+    wwyx => Vec4<4> [3, 3, 1, 0];
+    wwyy => Vec4<4> [3, 3, 1, 1];
+    wwyz => Vec4<4> [3, 3, 1, 2];
+    wwyw => Vec4<4> [3, 3, 1, 3];
+    wwzx => Vec4<4> [3, 3, 2, 0];
+    wwzy => Vec4<4> [3, 3, 2, 1];
+    wwzz => Vec4<4> [3, 3, 2, 2];
+    wwzw => Vec4<4> [3, 3, 2, 3];
+    wwwx => Vec4<4> [3, 3, 3, 0];
+    wwwy => Vec4<4> [3, 3, 3, 1];
+    wwwz => Vec4<4> [3, 3, 3, 2];
+    wwww => Vec4<4> [3, 3, 3, 3];
+}
+
+impl_swizzle_set! {
+    set_xy => Vec2 { x = x, y = y };
+    set_xz => Vec2 { x = x, z = y };
+    set_xw => Vec2 { x = x, w = y };
+    set_yx => Vec2 { y = x, x = y };
+    set_yz => Vec2 { y = x, z = y };
+    set_yw => Vec2 { y = x, w = y };
+    set_zx => Vec2 { z = x, x = y };
+    set_zy => Vec2 { z = x, y = y };
+    set_zw => Vec2 { z = x, w = y };
+    set_wx => Vec2 { w = x, x = y };
+    set_wy => Vec2 { w = x, y = y };
+    set_wz => Vec2 { w = x, z = y };
+    set_xyz => Vec3 { x = x, y = y, z = z };
+    set_xyw => Vec3 { x = x, y = y, w = z };
+    set_xzy => Vec3 { x = x, z = y, y = z };
+    set_xzw => Vec3 { x = x, z = y, w = z };
+    set_xwy => Vec3 { x = x, w = y, y = z };
+    set_xwz => Vec3 { x = x, w = y, z = z };
+    set_yxz => Vec3 { y = x, x = y, z = z };
+    set_yxw => Vec3 { y = x, x = y, w = z };
+    set_yzx => Vec3 { y = x, z = y, x = z };
+    set_yzw => Vec3 { y = x, z = y, w = z };
+    set_ywx => Vec3 { y = x, w = y, x = z };
+    set_ywz => Vec3 { y = x, w = y, z = z };
+    set_zxy => Vec3 { z = x, x = y, y = z };
+    set_zxw => Vec3 { z = x, x = y, w = z };
+    set_zyx => Vec3 { z = x, y = y, x = z };
+    set_zyw => Vec3 { z = x, y = y, w = z };
+    set_zwx => Vec3 { z = x, w = y, x = z };
+    set_zwy => Vec3 { z = x, w = y, y = z };
+    set_wxy => Vec3 { w = x, x = y, y = z };
+    set_wxz => Vec3 { w = x, x = y, z = z };
+    set_wyx => Vec3 { w = x, y = y, x = z };
+    set_wyz => Vec3 { w = x, y = y, z = z };
+    set_wzx => Vec3 { w = x, z = y, x = z };
+    set_wzy => Vec3 { w = x, z = y, y = z };
+    set_xyzw => Vec4 { x = x, y = y, z = z, w = w };
+    set_xywz => Vec4 { x = x, y = y, w = z, z = w };
+    set_xzyw => Vec4 { x = x, z = y, y = z, w = w };
+    set_xzwy => Vec4 { x = x, z = y, w = z, y = w };
+    set_xwyz => Vec4 { x = x, w = y, y = z, z = w };
+    set_xwzy => Vec4 { x = x, w = y, z = z, y = w };
+    set_yxzw => Vec4 { y = x, x = y, z = z, w = w };
+    set_yxwz => Vec4 { y = x, x = y, w = z, z = w };
+    set_yzxw => Vec4 { y = x, z = y, x = z, w = w };
+    set_yzwx => Vec4 { y = x, z = y, w = z, x = w };
+    set_ywxz => Vec4 { y = x, w = y, x = z, z = w };
+    set_ywzx => Vec4 { y = x, w = y, z = z, x = w };
+    set_zxyw => Vec4 { z = x, x = y, y = z, w = w };
+    set_zxwy => Vec4 { z = x, x = y, w = z, y = w };
+    set_zyxw => Vec4 { z = x, y = y, x = z, w = w };
+    set_zywx => Vec4 { z = x, y = y, w = z, x = w };
+    set_zwxy => Vec4 { z = x, w = y, x = z, y = w };
+    set_zwyx => Vec4 { z = x, w = y, y = z, x = w };
+    set_wxyz => Vec4 { w = x, x = y, y = z, z = w };
+    set_wxzy => Vec4 { w = x, x = y, z = z, y = w };
+    set_wyxz => Vec4 { w = x, y = y, x = z, z = w };
+    set_wyzx => Vec4 { w = x, y = y, z = z, x = w };
+    set_wzxy => Vec4 { w = x, z = y, x = z, y = w };
+    set_wzyx => Vec4 { w = x, z = y, y = z, x = w };
+}